@@ -0,0 +1,27 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2022, 13, Day13);
@@ -1,6 +1,14 @@
-use std::collections::HashSet;
+use aocutils::cartes::dim2::grid::Pos;
+use aocutils::cartes::dim2::sparse::SparseGrid2;
+use aocutils::cartes::grid::Grid;
 
-/// Naive approach of using hashset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Rock,
+    Sand,
+}
+
+/// Naive approach of using a hashmap backed sparse grid
 /// Perhaps using a growable grid would be faster
 /// But that's a puzzle for future me :)
 pub fn part2(input: &str) -> usize {
@@ -12,8 +20,7 @@ pub fn part2(input: &str) -> usize {
         polylines.push(polyline);
     }
 
-    let mut max_y = 0;
-    let mut grid = HashSet::new();
+    let mut grid = SparseGrid2::new();
     for polyline in polylines {
         let mut points = polyline.points.iter();
         let mut prev = points.next().unwrap();
@@ -24,8 +31,7 @@ pub fn part2(input: &str) -> usize {
             let ly = point.y.min(prev.y);
             for x in lx..=hx {
                 for y in ly..=hy {
-                    grid.insert((x, y));
-                    max_y = max_y.max(y);
+                    grid.insert(Pos::from((x as isize, y as isize)), Tile::Rock);
                 }
             }
 
@@ -33,34 +39,36 @@ pub fn part2(input: &str) -> usize {
         }
     }
 
+    let max_y = grid.bounds().map_or(0, |(_, max)| max.1);
     let (mut sx, mut sy) = (500, 0);
     let mut counter = 0;
+    let free = |grid: &SparseGrid2<Tile>, x, y| !grid.contains_pos(Pos::from((x, y)));
 
-    while !grid.contains(&(500, 0)) {
+    while free(&grid, 500, 0) {
         if sy == max_y + 1 {
             counter += 1;
-            grid.insert((sx, sy));
+            grid.insert(Pos::from((sx, sy)), Tile::Sand);
             (sx, sy) = (500, 0);
             continue;
         }
 
-        if !grid.contains(&(sx, sy + 1)) {
+        if free(&grid, sx, sy + 1) {
             sy += 1;
             continue;
         }
-        if !grid.contains(&(sx - 1, sy + 1)) {
+        if free(&grid, sx - 1, sy + 1) {
             sx -= 1;
             sy += 1;
             continue;
         }
-        if !grid.contains(&(sx + 1, sy + 1)) {
+        if free(&grid, sx + 1, sy + 1) {
             sx += 1;
             sy += 1;
             continue;
         }
 
         counter += 1;
-        grid.insert((sx, sy));
+        grid.insert(Pos::from((sx, sy)), Tile::Sand);
         (sx, sy) = (500, 0);
     }
 
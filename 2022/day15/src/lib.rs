@@ -0,0 +1,29 @@
+#![feature(new_range_api)]
+
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution, Unsolved};
+
+pub mod p1;
+pub mod p2;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = &'a str;
+    type Answer1 = Unsolved;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> Self::Answer1 {
+        Unsolved
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2022, 15, Day15);
@@ -0,0 +1,27 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution, Unsolved};
+
+pub mod p1;
+pub mod p2;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = p2::Network<'a>;
+    type Answer1 = Unsolved;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        p2::parse(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> Self::Answer1 {
+        Unsolved
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2022, 16, Day16);
@@ -1,9 +1,6 @@
-mod p1;
-mod p2;
+use aoc2022d16::DAY;
 
 fn main() {
-    let input = include_str!("input.txt");
-
     #[cfg(feature = "dot")]
     {
         use std::env;
@@ -18,7 +15,11 @@ fn main() {
             .write(true)
             .open(path)
             .unwrap();
-        let network = p2::parse(input);
+        let input = aocutils::runner::load_input_or_exit(&DAY);
+        let network = aoc2022d16::p2::parse(&input).unwrap_or_else(|e| {
+            eprintln!("{DAY}: {e}");
+            std::process::exit(1);
+        });
         let mut buffer = String::new();
         buffer.push_str("strict digraph {{\n");
         for (v, (r, adj)) in network {
@@ -34,9 +35,8 @@ fn main() {
         return;
     }
 
-    // println!("Day 16 part 1: {}", p1::part1(input));
     #[cfg(not(feature = "dot"))]
     {
-        println!("Day 16 part 2: {}", p2::part2(input));
+        aocutils::runner::main(&DAY);
     }
 }
@@ -1,24 +1,19 @@
 use std::collections::{BinaryHeap, HashMap};
 
-type Network<'a> = HashMap<&'a str, (u32, Vec<&'a str>)>;
-
-pub fn parse(input: &str) -> Network<'_> {
-    let mut network = HashMap::new();
-    for line in input.lines() {
-        let (valve_str, tunnels_str) = line.split_once(';').unwrap();
-        let mut valve_words = valve_str.split(' ');
-        let valve = valve_words.nth(1).unwrap();
-        let (_, rate) = valve_words.nth(2).unwrap().split_once('=').unwrap();
-        let rate = rate.parse::<u32>().unwrap();
-
-        let tunnels_str = tunnels_str.trim().split(' ').skip(4);
-        let tunnels: Vec<_> = tunnels_str
-            .map(|s| s.strip_suffix(',').unwrap_or(s).trim())
-            .collect();
-
-        network.insert(valve, (rate, tunnels));
-    }
-    network
+use aocutils::reading::scan::Pattern;
+use aocutils::reading::{ParseResult, Source};
+
+pub type Network<'a> = HashMap<&'a str, (u32, Vec<&'a str>)>;
+
+pub fn parse(input: &str) -> ParseResult<Network<'_>> {
+    let pattern = Pattern::new("Valve {} has flow rate={u32}; {_} {_} to {_} {list:, }");
+    let src = Source::new(input);
+    src.lines()
+        .map(|line| {
+            let (valve, rate, tunnels) = pattern.scan_in(&src, line)?;
+            Ok((valve, (rate, tunnels)))
+        })
+        .collect()
 }
 
 mod heuristics {
@@ -122,7 +117,7 @@ mod finale {
         }
     }
 
-    pub fn best_pressure(network: Network, path: NetHeu) -> u32 {
+    pub fn best_pressure(network: &Network, path: NetHeu) -> u32 {
         let openable_valves = network.values().filter(|(rate, _adj)| *rate > 0).count();
 
         let mut open = BinaryHeap::new();
@@ -341,10 +336,8 @@ mod finale {
         highest_pressure
     }
 }
-pub fn part2(input: &str) -> u32 {
-    let network = parse(input);
-
-    let heuristics = heuristics::net_heuristics(&network);
+pub fn part2(network: &Network) -> u32 {
+    let heuristics = heuristics::net_heuristics(network);
 
     finale::best_pressure(network, heuristics)
 }
@@ -366,7 +359,7 @@ Valve HH has flow rate=22; tunnel leads to valve GG
 Valve II has flow rate=0; tunnels lead to valves AA, JJ
 Valve JJ has flow rate=21; tunnel leads to valve II";
 
-        assert_eq!(part2(input), 1707);
+        assert_eq!(part2(&parse(input).unwrap()), 1707);
     }
 
     #[test]
@@ -375,6 +368,14 @@ Valve JJ has flow rate=21; tunnel leads to valve II";
 Valve BB has flow rate=2; tunnels lead to valves AA, CC
 Valve CC has flow rate=3; tunnels lead to valves BB, AA";
 
-        assert_eq!(part2(input), 120);
+        assert_eq!(part2(&parse(input).unwrap()), 120);
+    }
+
+    #[test]
+    fn parse_error() {
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB
+Valve BB has flow rate=x; tunnel leads to valve AA";
+        let err = parse(input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 24));
     }
 }
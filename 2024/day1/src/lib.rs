@@ -0,0 +1,100 @@
+use aocutils::reading::{ParseResult, fast};
+use aocutils::runner::{Day, Solution};
+
+pub const SAMPLE: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+/// Both columns of the input, sorted
+fn sorted_lists(input: &str) -> (Vec<u32>, Vec<u32>) {
+    let mut left = Vec::with_capacity(input.len() / 8);
+    let mut right = Vec::with_capacity(left.capacity());
+
+    let mut numbers = fast::uints::<u32>(input.as_bytes());
+    while let (Some(l), Some(r)) = (numbers.next(), numbers.next()) {
+        left.push(l);
+        right.push(r);
+    }
+    left.sort();
+    right.sort();
+    (left, right)
+}
+
+pub fn part1(input: &str) -> u32 {
+    let (left, right) = sorted_lists(input);
+
+    let mut sum = 0;
+    for i in 0..left.len() {
+        sum += left[i].abs_diff(right[i]);
+    }
+    sum
+}
+
+pub fn part2(input: &str) -> u32 {
+    let (left, right) = sorted_lists(input);
+
+    let mut sum = 0;
+
+    // Clippy does not account for the `continue` statement inside the loop when suggesting a fix;
+    #[allow(clippy::needless_range_loop)]
+    for i in 0..left.len() {
+        let target = left[i];
+        let index = right.binary_search(&target);
+        if index.is_err() {
+            continue;
+        }
+        let index = index.unwrap();
+
+        let mut l_idx = index;
+        let mut r_idx = index;
+        while l_idx > 0 && right[l_idx - 1] == target {
+            l_idx -= 1;
+        }
+        while r_idx + 1 < right.len() && right[r_idx + 1] == target {
+            r_idx += 1;
+        }
+        let count = r_idx - l_idx + 1;
+        sum += count as u32 * target;
+    }
+    sum
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = &'a str;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 1, Day1);
+
+#[cfg(test)]
+mod d1 {
+    use super::*;
+
+    #[test]
+    fn p1() {
+        assert_eq!(part1(SAMPLE), 11);
+    }
+
+    #[test]
+    fn p2() {
+        assert_eq!(part2(SAMPLE), 31);
+    }
+}
@@ -0,0 +1,45 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub const SAMPLE: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = &'a str;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::optim::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::optim::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 10, Day10)
+    .with_samples(&[SAMPLE])
+    .with_variants(&[
+        aocutils::variant!(One, "naive", p1::naive::part1),
+        aocutils::variant!(One, "optim", p1::optim::part1),
+        aocutils::variant!(Two, "naive", p2::naive::part2),
+        aocutils::variant!(Two, "optim", p2::optim::part2),
+    ]);
+
+aocutils::cross_check!(DAY);
@@ -245,6 +245,7 @@ pub mod optim {
 mod optim_naive_test {
     use super::*;
     use crate::*;
+    use aocutils::runner::check::test_input;
 
     #[test]
     fn sample() {
@@ -253,7 +254,9 @@ mod optim_naive_test {
 
     #[test]
     fn input() {
-        let input = include_str!("input.txt");
-        assert_eq!(naive::part1(input), optim::part1(input));
+        let Some(input) = test_input(&crate::DAY) else {
+            return;
+        };
+        assert_eq!(naive::part1(&input), optim::part1(&input));
     }
 }
@@ -152,10 +152,13 @@ pub mod optim {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aocutils::runner::check::test_input;
 
     #[test]
     fn optim_naive() {
-        let input = include_str!("input.txt");
-        assert_eq!(naive::part2(input), optim::part2(input));
+        let Some(input) = test_input(&crate::DAY) else {
+            return;
+        };
+        assert_eq!(naive::part2(&input), optim::part2(&input));
     }
 }
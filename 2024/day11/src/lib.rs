@@ -0,0 +1,29 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub const SAMPLE: &str = "125 17";
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::naive::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::naive::part2(input, 75)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 11, Day11);
@@ -0,0 +1,45 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub const SAMPLE: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = &'a str;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::naive::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::naive::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 12, Day12)
+    .with_samples(&[SAMPLE])
+    .with_variants(&[
+        aocutils::variant!(Two, "naive", p2::naive::part2),
+        aocutils::variant!(Two, "regions", p2::regions::part2),
+    ]);
+
+aocutils::cross_check!(DAY);
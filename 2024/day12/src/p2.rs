@@ -222,3 +222,40 @@ EEEEE";
         }
     }
 }
+
+pub mod regions {
+    use aocutils::cartes::dim2::grid::Grid2;
+    use aocutils::cartes::flood::Connectivity;
+
+    /// Same as [naive][super::naive] but measuring the regions with aocutils
+    pub fn part2(input: &str) -> u32 {
+        let garden = Grid2::from_str_2(input, Some);
+        garden
+            .components(Connectivity::Adjacent, |a, b| a == b)
+            .regions()
+            .map(|region| (region.area() * region.sides()) as u32)
+            .sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::*;
+
+        #[test]
+        fn sample() {
+            assert_eq!(part2(SAMPLE), 1206);
+        }
+
+        #[test]
+        fn enclosed() {
+            let input = "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA";
+            assert_eq!(part2(input), 368);
+        }
+    }
+}
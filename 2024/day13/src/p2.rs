@@ -1,4 +1,6 @@
 pub mod naive {
+    use aocutils::reading::numbers::ints_n;
+
     pub fn compute(v1: (i64, i64), v2: (i64, i64), target: (i64, i64)) -> Option<(i64, i64)> {
         // Linear algebra this thing
         let recip_det = v1.0 * v2.1 - v1.1 * v2.0;
@@ -11,27 +13,16 @@ pub mod naive {
     }
 
     pub fn part2(input: &str) -> i64 {
-        // Im sleepy and I frankly dont care enough about how bad this parsing looks
         let x = input
             .split("\n\n")
             .map(|x| {
-                fn parse(inp: &str) -> (i64, i64) {
-                    let (a, b) = inp.split_once(':').unwrap().1.split_once(',').unwrap();
-                    let f = |e: &str| {
-                        e.split_once('+')
-                            .unwrap_or_else(|| e.split_once('=').unwrap())
-                            .1
-                            .trim()
-                            .parse()
-                            .unwrap()
-                    };
-                    (f(a), f(b))
-                }
-                let mut y = x.split('\n').map(parse);
-                let a = y.next().unwrap();
-                let b = y.next().unwrap();
-                let c = y.next().unwrap();
-                (a, b, (c.0 + 10000000000000, c.1 + 10000000000000))
+                let [ax, ay, bx, by, cx, cy] =
+                    ints_n::<i64, 6>(x).unwrap_or_else(|e| panic!("{e}"));
+                (
+                    (ax, ay),
+                    (bx, by),
+                    (cx + 10000000000000, cy + 10000000000000),
+                )
             })
             .map(|(a, b, c)| compute(a, b, c))
             .map(|res| res.map(|(x, y)| 3 * x + y).unwrap_or(0))
@@ -0,0 +1,40 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution, Unsolved};
+
+pub mod p1;
+pub mod p2;
+
+pub const SAMPLE: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = &'a str;
+    type Answer1 = i32;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::naive::part1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Self::Answer2 {
+        Unsolved
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 14, Day14);
@@ -1,25 +1,12 @@
-pub mod p1;
-pub mod p2;
-
-pub const SAMPLE: &str = "p=0,4 v=3,-3
-p=6,3 v=-1,-3
-p=10,3 v=-1,2
-p=2,0 v=2,-1
-p=0,0 v=1,3
-p=3,0 v=-2,-2
-p=7,6 v=-1,-3
-p=3,0 v=-1,-2
-p=9,3 v=2,3
-p=7,3 v=-1,2
-p=2,4 v=2,-3
-p=9,5 v=-3,-3";
+use aoc2024d14::{DAY, SAMPLE, p1, p2};
 
 fn main() {
     let input = if cfg!(feature = "p1sample") {
-        SAMPLE
+        SAMPLE.to_string()
     } else {
-        include_str!("input.txt")
+        aocutils::runner::load_input_or_exit(&DAY)
     };
-    println!("Part 1: {}", p1::naive::part1(input));
-    p2::naive::part2(input);
+    println!("Part 1: {}", p1::naive::part1(&input));
+    // Part 2 is found by eye from the rendered frames
+    p2::naive::part2(&input);
 }
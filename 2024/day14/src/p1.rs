@@ -1,17 +1,13 @@
 pub mod naive {
     use aocutils::num::BitwiseAvg as _;
+    use aocutils::reading::numbers::ints_n;
 
     type Int = i32;
     type Vec2 = aocutils::cartes::dim2::vec::Vec2<Int>;
 
-    fn parse_vector(input: &str) -> Vec2 {
-        let (x, y) = input.split_once('=').unwrap().1.split_once(',').unwrap();
-        From::from((x.trim().parse().unwrap(), y.trim().parse().unwrap()))
-    }
     fn parse_line(input: &str) -> (Vec2, Vec2) {
-        let (p, v) = input.split_once(' ').unwrap();
-        let (p, v) = (parse_vector(p), parse_vector(v));
-        (p, v)
+        let [px, py, vx, vy] = ints_n(input).unwrap_or_else(|e| panic!("{e}"));
+        (Vec2::from((px, py)), Vec2::from((vx, vy)))
     }
     fn parse_input(input: &str) -> (Vec<Vec2>, Vec<Vec2>) {
         let lines = input.lines().count();
@@ -1,7 +1,7 @@
 use aocutils::cartes::dim2::dir::Direction;
 use aocutils::cartes::dim2::grid::{Grid2, Pos};
 use aocutils::cartes::dim2::vec::Vec2;
-use aocutils::cartes::grid::Grid as _;
+use aocutils::cartes::grid::{Grid as _, GridMut as _};
 use aocutils::optim::prelude::*;
 
 type Grid = Grid2<Cell>;
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = ([p1::Reg; 3], Vec<u8>);
+    type Answer1 = String;
+    type Answer2 = p2::Reg;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        p1::parse(input)
+    }
+
+    fn part1((reg, program): &Self::Input<'_>) -> Self::Answer1 {
+        p1::run(*reg, program)
+    }
+
+    fn part2((_, program): &Self::Input<'_>) -> Self::Answer2 {
+        p2::search(program)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 17, Day17);
@@ -1,22 +1,28 @@
+use aocutils::reading::sections::parse_sections;
+use aocutils::reading::{ParseResult, Source};
+
 pub type Reg = u64;
-pub fn parse(input: &str) -> ([Reg; 3], Vec<u8>) {
-    let mut lines = input.lines();
-    let (_, ra) = lines.next().unwrap().split_once(':').unwrap();
-    let (_, rb) = lines.next().unwrap().split_once(':').unwrap();
-    let (_, rc) = lines.next().unwrap().split_once(':').unwrap();
-    let (ra, rb, rc) = (
-        ra.trim().parse::<Reg>().unwrap(),
-        rb.trim().parse::<Reg>().unwrap(),
-        rc.trim().parse::<Reg>().unwrap(),
-    );
-
-    let (_, prog) = lines.nth(1).unwrap().split_once(':').unwrap();
-    let prog: Vec<_> = prog
-        .trim()
-        .split(',')
-        .map(|e| e.parse::<u8>().unwrap())
-        .collect();
-    ([ra, rb, rc], prog)
+
+fn parse_registers(block: &str) -> ParseResult<[Reg; 3]> {
+    let src = Source::new(block);
+    let mut lines = src.lines();
+    let mut reg = [0; 3];
+    for r in reg.iter_mut() {
+        let line = lines.next().ok_or_else(|| src.eof("expected a register"))?;
+        let (_, value) = src.split_once(line, ":")?;
+        *r = src.parse(value)?;
+    }
+    Ok(reg)
+}
+
+fn parse_program(block: &str) -> ParseResult<Vec<u8>> {
+    let src = Source::new(block);
+    let (_, prog) = src.split_once(block, ":")?;
+    prog.trim().split(',').map(|e| src.parse(e)).collect()
+}
+
+pub fn parse(input: &str) -> ParseResult<([Reg; 3], Vec<u8>)> {
+    parse_sections(input, (parse_registers, parse_program))
 }
 
 #[inline]
@@ -34,7 +40,12 @@ fn mod8(n: Reg) -> Reg {
 }
 
 pub fn part1(input: &str) -> String {
-    let (mut reg, program) = parse(input);
+    let (reg, program) = parse(input).unwrap_or_else(|e| panic!("{e}"));
+    run(reg, &program)
+}
+
+/// Runs the program, returning its output
+pub fn run(mut reg: [Reg; 3], program: &[u8]) -> String {
     let mut ins_ptr = 0;
 
     let mut output = vec![];
@@ -81,6 +92,14 @@ pub fn part1(input: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aocutils::runner::check::test_input;
+
+    #[test]
+    fn invalid() {
+        let err = parse("Register A: 729\nRegister B: x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 13));
+        assert!(parse("Register A: 1\nRegister B: 0\nRegister C: 0\n").is_err());
+    }
 
     #[test]
     fn sample() {
@@ -96,7 +115,9 @@ Program: 0,1,5,4,3,0";
     /// This test is written after my completion of part 1.
     #[test]
     fn actual_result() {
-        let input = include_str!("input.txt");
-        assert_eq!(part1(input), "1,3,5,1,7,2,5,1,6");
+        let Some(input) = test_input(&crate::DAY) else {
+            return;
+        };
+        assert_eq!(part1(&input), "1,3,5,1,7,2,5,1,6");
     }
 }
@@ -1,16 +1,9 @@
-pub type Reg = u64;
+use aocutils::reading::ParseResult;
 
-pub fn parse(input: &str) -> Vec<u8> {
-    let mut lines = input.lines();
-    lines.nth(2);
+pub type Reg = u64;
 
-    let (_, prog) = lines.nth(1).unwrap().split_once(':').unwrap();
-    let prog: Vec<_> = prog
-        .trim()
-        .split(',')
-        .map(|e| e.parse::<u8>().unwrap())
-        .collect();
-    prog
+pub fn parse(input: &str) -> ParseResult<Vec<u8>> {
+    crate::p1::parse(input).map(|(_, program)| program)
 }
 
 #[inline]
@@ -96,23 +89,30 @@ fn search_manual(out_ptr: usize, program: &[u8], reg_a: Reg, found: &mut Vec<Reg
 }
 
 pub fn part2_manual(input: &str) -> Reg {
-    let program = parse(input);
+    search(&parse(input).unwrap_or_else(|e| panic!("{e}")))
+}
+
+/// Lowest value of register A making the program output itself
+pub fn search(program: &[u8]) -> Reg {
     let mut output = vec![];
-    search_manual(program.len() - 1, &program, 0, &mut output);
+    search_manual(program.len() - 1, program, 0, &mut output);
     *output.iter().min().unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aocutils::runner::check::test_input;
 
     #[test]
     fn manual() {
-        let input = include_str!("input.txt");
+        let Some(input) = test_input(&crate::DAY) else {
+            return;
+        };
         // Value acquired after solving the puzzle
-        let solution = part2_manual(input);
+        let solution = part2_manual(&input);
         dbg!(solution);
-        assert!(simulate(solution, &parse(input)));
+        assert!(simulate(solution, &parse(&input).unwrap()));
     }
 
     #[test]
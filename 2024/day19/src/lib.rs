@@ -0,0 +1,49 @@
+#![feature(iter_intersperse)]
+#![allow(dead_code)]
+
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub const SAMPLE: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::dp::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 19, Day19)
+    .with_samples(&[SAMPLE])
+    .with_variants(&[
+        aocutils::variant!(One, "naive", p1::naive::part1),
+        aocutils::variant!(One, "dtree", p1::dtree::part1),
+        aocutils::variant!(One, "dp", p1::dp::part1),
+    ]);
+
+aocutils::cross_check!(DAY);
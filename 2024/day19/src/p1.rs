@@ -169,6 +169,7 @@ mod tests {
     use crate::SAMPLE;
 
     use super::*;
+    use aocutils::runner::check::test_input;
 
     #[test]
     fn sample() {
@@ -189,7 +190,9 @@ rgurg";
 
     #[test]
     fn regex() {
-        let input = include_str!("input.txt");
+        let Some(input) = test_input(&crate::DAY) else {
+            return;
+        };
         let mut input = input.split("\n\n");
         let towels = input.next().unwrap().trim();
         let towel_re = ::std::iter::once("^(")
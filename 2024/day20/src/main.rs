@@ -1,24 +1,3 @@
-mod p1;
-mod p2;
-
-pub const SAMPLE: &str = "###############
-#...#...#.....#
-#.#.#.#.#.###.#
-#S#...#.#.#...#
-#######.#.#.###
-#######.#.#...#
-#######.#.###.#
-###..E#...#...#
-###.#######.###
-#...###...#...#
-#.#####.#.###.#
-#.#...#.#.#...#
-#.#.#.#.#.#.###
-#...#...#...###
-###############";
-
 fn main() {
-    let input = include_str!("input.txt");
-    println!("Day 20 part 1: {}", p1::part1(input));
-    println!("Day 20 part 2: {}", p2::part2(input));
+    aocutils::runner::main(&aoc2024d20::DAY);
 }
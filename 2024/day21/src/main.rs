@@ -1,6 +1,3 @@
-mod p1;
-
 fn main() {
-    let input = include_str!("input.txt");
-    println!("Day 21 part 1: {}", p1::part1(input));
+    aocutils::runner::main(&aoc2024d21::DAY);
 }
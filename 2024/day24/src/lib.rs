@@ -0,0 +1,27 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution, Unsolved};
+
+pub mod p1;
+pub mod p2;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::part1(input)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Self::Answer2 {
+        Unsolved
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 24, Day24);
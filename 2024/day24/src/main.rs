@@ -1,8 +1,12 @@
-mod p1;
-mod p2;
+use aoc2024d24::{DAY, p2};
+use aocutils::runner::{self, Part};
 
 fn main() {
-    let input = include_str!("input.txt");
-    println!("Day 24 part 1: {}", p1::part1(input));
-    p2::part2(input);
+    let input = runner::load_input_or_exit(&DAY);
+    if let Err(e) = runner::print_day(&DAY, &input, Part::iter_all()) {
+        eprintln!("{}: invalid input, {e}", DAY);
+        std::process::exit(1);
+    }
+    // Part 2 is solved by hand from the generated DOT file
+    p2::part2(&input);
 }
@@ -0,0 +1,121 @@
+use aocutils::reading::ParseResult;
+use aocutils::reading::comb::{PResult, delimited, separated_pair, tag, unsigned};
+use aocutils::runner::{Day, Solution};
+
+pub const SAMPLE: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+pub const SAMPLE2: &str =
+    "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+fn parse_mul(input: &str) -> PResult<&str, (u32, u32)> {
+    delimited(
+        tag("mul("),
+        separated_pair(unsigned, tag(","), unsigned),
+        tag(")"),
+    )(input)
+}
+
+pub fn part1(input: &str) -> u32 {
+    let mut input = input;
+    let mut sum = 0;
+    while let Some(i) = input.find("mul(") {
+        input = &input[i..];
+        match parse_mul(input) {
+            Ok((rest, (left, right))) => {
+                input = rest;
+                sum += left * right;
+            }
+            Err(_) => input = &input[4..],
+        }
+    }
+    sum
+}
+
+// Imma just not use nom cuz lazy and I have skill issue :p
+pub fn part2(input: &str) -> u32 {
+    let mut i = 0;
+
+    let mut enabled = true;
+    let mut sum = 0;
+    // Shortest mul operation is mul(x,x)
+    // which takes a i..=i+7 window
+    'out: while i + 7 < input.len() {
+        if &input[i..i + 2] == "do" {
+            if &input[i..i + 7] == "don't()" {
+                enabled = false;
+                i += 7;
+                continue;
+            }
+            if &input[i..i + 4] == "do()" {
+                enabled = true;
+                i += 4;
+                continue;
+            }
+        }
+        if enabled && &input[i..i + 4] == "mul(" {
+            let mut j = i + 4;
+            let mut comma = 0;
+            while j < input.len() {
+                let c = &input[j..j + 1];
+                match c {
+                    "," => comma = j,
+                    "0" | "1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9" => {}
+                    ")" => break,
+                    _ => {
+                        i += 1;
+                        continue 'out;
+                    }
+                }
+                j += 1;
+            }
+            if comma == 0 {
+                // Invalid input
+                i += 1;
+                continue;
+            }
+
+            let left = &input[i + 4..comma].parse::<u32>().unwrap();
+            let right = &input[comma + 1..j].parse::<u32>().unwrap();
+            sum += left * right;
+        }
+
+        i += 1;
+    }
+    sum
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = &'a str;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 3, Day3);
+
+#[cfg(test)]
+mod d5 {
+    use super::*;
+
+    #[test]
+    fn p1() {
+        assert_eq!(part1(SAMPLE), 161);
+    }
+
+    #[test]
+    fn p2() {
+        assert_eq!(part2(SAMPLE2), 48);
+    }
+}
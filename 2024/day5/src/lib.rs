@@ -0,0 +1,65 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub const SAMPLE: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = &'a str;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::optim::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::optim::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 5, Day5)
+    .with_samples(&[SAMPLE])
+    .with_variants(&[
+        aocutils::variant!(One, "naive", p1::naive::part1),
+        aocutils::variant!(One, "optim", p1::optim::part1),
+        aocutils::variant!(Two, "naive", p2::naive::part2),
+        aocutils::variant!(Two, "optim", p2::optim::part2),
+    ]);
+
+aocutils::cross_check!(DAY);
@@ -129,6 +129,7 @@ pub mod optim {
 mod test {
     use super::*;
     use crate::*;
+    use aocutils::runner::check::test_input;
 
     #[test]
     fn sample() {
@@ -137,7 +138,9 @@ mod test {
 
     #[test]
     fn optim_naive() {
-        let input = include_str!("input.txt");
-        assert_eq!(optim::part1(input), naive::part1(input));
+        let Some(input) = test_input(&crate::DAY) else {
+            return;
+        };
+        assert_eq!(optim::part1(&input), naive::part1(&input));
     }
 }
@@ -0,0 +1,71 @@
+use aocutils::rand::Rng;
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub const SAMPLE: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+pub mod p1;
+pub mod p2;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::optim::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::optim::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 7, Day7)
+    .with_samples(&[SAMPLE])
+    .with_generator(generate)
+    .with_variants(&[
+        aocutils::variant!(One, "naive", p1::naive::part1),
+        aocutils::variant!(One, "optim", p1::optim::part1),
+        aocutils::variant!(Two, "naive", p2::naive::part2),
+        aocutils::variant!(Two, "optim", p2::optim::part2),
+    ]);
+
+/// Random equations, about half of which can be made true with all three operators
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(1..20))
+        .map(|_| {
+            let nums: Vec<u64> = (0..rng.range(1..7)).map(|_| rng.range(1..100)).collect();
+            let target = if rng.bool() {
+                nums[1..]
+                    .iter()
+                    .fold(nums[0], |acc, &n| match rng.range(0..3) {
+                        0 => acc + n,
+                        1 => acc * n,
+                        _ => acc * 10u64.pow(n.ilog10() + 1) + n,
+                    })
+            } else {
+                rng.range(1..1_000_000)
+            };
+            let nums: Vec<_> = nums.iter().map(u64::to_string).collect();
+            format!("{target}: {}", nums.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+aocutils::cross_check!(DAY, random: 200);
@@ -88,6 +88,7 @@ pub mod optim {
 mod test {
     use super::*;
     use crate::*;
+    use aocutils::runner::check::test_input;
 
     #[test]
     fn sample() {
@@ -97,7 +98,9 @@ mod test {
 
     #[test]
     fn optim_naive() {
-        let input = include_str!("input.txt");
-        assert_eq!(naive::part2(input), optim::part2(input));
+        let Some(input) = test_input(&crate::DAY) else {
+            return;
+        };
+        assert_eq!(naive::part2(&input), optim::part2(&input));
     }
 }
@@ -0,0 +1,38 @@
+#![feature(let_chains, random)]
+
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub const SAMPLE: &str = "2333133121414131402";
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::optim::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::naive::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2024, 9, Day9)
+    .with_samples(&[SAMPLE])
+    .with_variants(&[
+        aocutils::variant!(One, "naive", p1::naive::part1),
+        aocutils::variant!(One, "optim", p1::optim::part1),
+    ]);
+
+aocutils::cross_check!(DAY);
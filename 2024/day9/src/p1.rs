@@ -133,6 +133,7 @@ pub mod optim {
 mod test {
     use super::*;
     use crate::*;
+    use aocutils::runner::check::test_input;
 
     #[test]
     fn sample() {
@@ -141,7 +142,9 @@ mod test {
 
     #[test]
     fn optim_naive() {
-        let input = include_str!("input.txt");
-        assert_eq!(naive::part1(input), optim::part1(input));
+        let Some(input) = test_input(&crate::DAY) else {
+            return;
+        };
+        assert_eq!(naive::part1(&input), optim::part1(&input));
     }
 }
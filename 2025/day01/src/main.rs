@@ -1,8 +1,3 @@
-mod p1;
-mod p2;
-
 fn main() {
-    let input = include_str!("input.txt");
-    println!("Part 1: {}", p1::part1(input));
-    println!("Part 2: {}", p2::part2(input));
+    aocutils::runner::main(&aoc2025d1::DAY);
 }
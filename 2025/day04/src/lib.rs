@@ -0,0 +1,48 @@
+#![deny(clippy::all)]
+
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub const SAMPLE: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = &'a str;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2025, 4, Day4)
+    .with_samples(&[SAMPLE])
+    .with_variants(&[
+        aocutils::variant!(Two, "naive", p2::part2),
+        aocutils::variant!(Two, "bits", p2::bits::part2),
+    ]);
+
+aocutils::cross_check!(DAY);
+aocutils::sample_tests!(DAY);
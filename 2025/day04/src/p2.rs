@@ -46,6 +46,28 @@ pub fn part2(input: &str) -> u32 {
     sum
 }
 
+pub mod bits {
+    use aocutils::cartes::dim2::bitgrid::BitGrid2;
+    use aocutils::cartes::dim2::grid::Grid2;
+    use aocutils::cartes::flood::Connectivity;
+
+    /// Removes every accessible roll at once with word-parallel neighbour counts
+    pub fn part2(input: &str) -> u32 {
+        let mut rolls = BitGrid2::from_grid(&Grid2::from_str_2(input, Some), |&b| b == b'@');
+        let mut sum = 0;
+        loop {
+            let crowded = rolls.neighbour_counts(Connectivity::Full).at_least(4);
+            let removable = &rolls & &!&crowded;
+            if removable.is_empty() {
+                break;
+            }
+            sum += removable.count_ones() as u32;
+            rolls ^= &removable;
+        }
+        sum
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -64,4 +86,9 @@ mod test {
 @.@.@@@.@.";
         assert_eq!(part2(input), 43);
     }
+
+    #[test]
+    fn bits() {
+        assert_eq!(bits::part2(crate::SAMPLE), 43);
+    }
 }
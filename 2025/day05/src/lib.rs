@@ -1,6 +1,9 @@
 use std::cell::RefCell;
 use std::rc::{Rc, Weak};
 
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
 pub mod p1;
 pub mod p2;
 
@@ -106,3 +109,24 @@ fn intersects_node(node: &Rc<RefCell<AugmentedTreeNode>>, query: u64) -> bool {
     }
 }
 
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = &'a str;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::part2(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2025, 5, Day5);
@@ -0,0 +1,27 @@
+use aocutils::reading::ParseResult;
+use aocutils::runner::{Day, Solution};
+
+pub mod p1;
+pub mod p2;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<p1::Machine>;
+    type Answer1 = i32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+        p1::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+        p1::min_presses(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2 {
+        p2::min_joltage_presses(input)
+    }
+}
+
+pub const DAY: Day = aocutils::day!(2025, 10, Day10);
@@ -1,21 +1,83 @@
 use std::collections::BinaryHeap;
 
 use aocutils::linalg::matrix::{Matrix, SquareMatrix};
+use aocutils::reading::{ParseResult, Source};
+
+/// One line of the manual: `[.##.] (3) (1,3) (2) {3,5,4,7}`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Machine {
+    /// Target light states, on being `#`
+    pub lights: Vec<bool>,
+    /// Indices of the lights (or joltage counters) each button toggles
+    pub buttons: Vec<Vec<usize>>,
+    /// Target joltage of each counter
+    pub joltage: Vec<i32>,
+}
+
+/// The contents of `at` between `open` and `close`
+fn delimited<'a>(src: &Source<'a>, at: &'a str, open: &str, close: &str) -> ParseResult<&'a str> {
+    let inner = src.strip_prefix(at, open)?;
+    let message = format!("expected {close:?}");
+    src.expect(inner.strip_suffix(close), at, &message)
+}
+
+fn parse_lights<'a>(src: &Source<'a>, lights: &'a str) -> ParseResult<Vec<bool>> {
+    let inner = delimited(src, lights, "[", "]")?;
+    inner
+        .char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(src.error(&inner[i..i + c.len_utf8()], "expected '.' or '#'")),
+        })
+        .collect()
+}
+
+fn parse_button<'a>(src: &Source<'a>, button: &'a str, lights: usize) -> ParseResult<Vec<usize>> {
+    delimited(src, button, "(", ")")?
+        .split(',')
+        .map(|light| {
+            let idx = src.parse(light)?;
+            if idx >= lights {
+                return Err(src.error(light, format!("there are only {lights} lights")));
+            }
+            Ok(idx)
+        })
+        .collect()
+}
 
-fn parse_button(button: &str) -> impl Iterator<Item = usize> {
-    button[1..button.len() - 1]
+fn parse_machine<'a>(src: &Source<'a>, line: &'a str) -> ParseResult<Machine> {
+    let (lights, rest) = src.split_once(line, " ")?;
+    let (buttons, joltage_str) = src.expect(rest.rsplit_once(' '), rest, "expected joltages")?;
+    let lights = parse_lights(src, lights)?;
+    let buttons = buttons
+        .split(' ')
+        .map(|button| parse_button(src, button, lights.len()))
+        .collect::<ParseResult<_>>()?;
+    let joltage: Vec<_> = delimited(src, joltage_str, "{", "}")?
         .split(',')
-        .map(|s| s.parse::<usize>().unwrap())
+        .map(|jolt| src.parse(jolt))
+        .collect::<ParseResult<_>>()?;
+    if joltage.len() != lights.len() {
+        let message = format!("expected {} joltages", lights.len());
+        return Err(src.error(joltage_str, message));
+    }
+    Ok(Machine {
+        lights,
+        buttons,
+        joltage,
+    })
 }
-fn parse_lights(lights: &str) -> impl Iterator<Item = bool> {
-    lights[1..lights.len() - 1].chars().map(|c| c == '#')
+
+pub fn parse(input: &str) -> ParseResult<Vec<Machine>> {
+    let src = Source::new(input);
+    src.lines().map(|line| parse_machine(&src, line)).collect()
 }
 
-fn try_matrix(buttons: &str, lights_buf: &mut Vec<i32>) -> Option<i32> {
+fn try_matrix(buttons: &[Vec<usize>], lights_buf: &mut Vec<i32>) -> Option<i32> {
     let lights_n = lights_buf.len();
 
-    let buttons = buttons.trim().split(' ');
-    let buttons_n = buttons.clone().count(); // Adjust sizes to make square matrix
+    let buttons_n = buttons.len(); // Adjust sizes to make square matrix
     let mut extra_btns = 0;
     if lights_n < buttons_n {
         for _ in lights_n..buttons_n {
@@ -41,11 +103,11 @@ fn try_matrix(buttons: &str, lights_buf: &mut Vec<i32>) -> Option<i32> {
         lights_n + extra_lights,
         buttons_n + extra_btns,
     ));
-    for (i, button) in buttons.enumerate() {
+    for (i, button) in buttons.iter().enumerate() {
         for j in 0..lights_n {
             *matrix.get_mut(j, i) = 0;
         }
-        for light in parse_button(button) {
+        for &light in button {
             *matrix.get_mut(light, i) = 1;
         }
     }
@@ -89,7 +151,7 @@ impl Ord for BfsState {
         self.depth.cmp(&other.depth).reverse()
     }
 }
-fn try_bfs(button: &str, lights: &mut Vec<i32>) -> i32 {
+fn try_bfs(buttons: &[Vec<usize>], lights: &mut Vec<i32>) -> i32 {
     let mut open = BinaryHeap::new();
     open.push(BfsState {
         light_state: vec![0; lights.len()],
@@ -97,16 +159,14 @@ fn try_bfs(button: &str, lights: &mut Vec<i32>) -> i32 {
         depth: 0,
     });
 
-    let button_itr = button.trim().split(' ').map(|b| parse_button(b));
-
     while let Some(state) = open.pop() {
         if state.light_state == lights.as_slice() {
             return state.depth as i32;
         }
 
-        for button in button_itr.clone() {
+        for button in buttons {
             let mut next = state.clone();
-            for light_toggled in button {
+            for &light_toggled in button {
                 // 0 -> 1 
                 // 1 -> 0
                 next.light_state[light_toggled] = 1 - next.light_state[light_toggled];
@@ -119,18 +179,19 @@ fn try_bfs(button: &str, lights: &mut Vec<i32>) -> i32 {
 }
 
 pub fn part1(input: &str) -> i32 {
+    min_presses(&parse(input).unwrap_or_else(|e| panic!("{e}")))
+}
+
+pub fn min_presses(machines: &[Machine]) -> i32 {
     let mut presses = 0;
     let mut lights = vec![];
-    for line in input.lines() {
-        let (lights_str, line) = line.split_once(' ').unwrap();
-        let (buttons, _) = line.split_at(line.rfind(' ').unwrap());
-
+    for machine in machines {
         // Parse target light state
         lights.clear();
-        for light in parse_lights(lights_str) {
+        for &light in &machine.lights {
             lights.push(light as i32);
         }
-        if let Some(p) = try_matrix(buttons, &mut lights) {
+        if let Some(p) = try_matrix(&machine.buttons, &mut lights) {
             presses += p;
             continue;
         }
@@ -138,10 +199,10 @@ pub fn part1(input: &str) -> i32 {
         // Non matrix approach
         // Brute force this thing?
         lights.clear();
-        for light in parse_lights(lights_str) {
+        for &light in &machine.lights {
             lights.push(light as i32);
         }
-        presses += try_bfs(buttons, &mut lights);
+        presses += try_bfs(&machine.buttons, &mut lights);
     }
     presses
 }
@@ -157,4 +218,19 @@ mod test {
 [.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
         assert_eq!(part1(input), 7);
     }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse("[.##.] (3) (1,3) (2)\n[.#] (0) (1,x) {3,5}").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 18));
+        assert_eq!(err.message, "expected \"{\"");
+
+        let err = parse("[.#] (0) (1,x) {3,5}").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (1, 13, 1));
+        let err = parse("[.#] (0) (2) {3,5}").unwrap_err();
+        assert_eq!(err.column, 11);
+        assert_eq!(err.message, "there are only 2 lights");
+        let err = parse("[.#] (0) 1,0) {3,5}").unwrap_err();
+        assert_eq!((err.column, err.len), (10, 4));
+    }
 }
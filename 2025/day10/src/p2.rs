@@ -4,16 +4,7 @@ use aocutils::linalg::matrix::Matrix;
 use aocutils::linalg::matrix::row_operations::{div, mul_sub, swap};
 use smallvec::{SmallVec, smallvec};
 
-fn parse_button(button: &str) -> impl Iterator<Item = usize> {
-    button[1..button.len() - 1]
-        .split(',')
-        .map(|s| s.parse::<usize>().unwrap())
-}
-fn parse_joltage(joltage_s: &str) -> impl Iterator<Item = i32> {
-    joltage_s[1..joltage_s.len() - 1]
-        .split(',')
-        .map(|s| s.parse::<i32>().unwrap())
-}
+use crate::p1::{Machine, parse};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum GaussElimResult<N> {
@@ -166,11 +157,10 @@ pub fn print_matrix<N: core::fmt::Debug>(matrix: &Matrix<N>) {
     }
 }
 
-fn matrix_ge(buttons: &str, jolt_counters: &mut Vec<i32>) -> GaussElimResult<f64> {
+fn matrix_ge(buttons: &[Vec<usize>], jolt_counters: &mut Vec<i32>) -> GaussElimResult<f64> {
     let counters_n = jolt_counters.len();
 
-    let buttons = buttons.trim().split(' ');
-    let buttons_n = buttons.clone().count();
+    let buttons_n = buttons.len();
 
     // Target-joltage matrix
     let mut joltage_matrix = Matrix::new(0., counters_n, 1);
@@ -180,11 +170,11 @@ fn matrix_ge(buttons: &str, jolt_counters: &mut Vec<i32>) -> GaussElimResult<f64
 
     // Button-system matrix
     let mut matrix = Matrix::new(0., counters_n, buttons_n);
-    for (c, button) in buttons.enumerate() {
+    for (c, button) in buttons.iter().enumerate() {
         for r in 0..counters_n {
             *matrix.get_mut(r, c) = 0.;
         }
-        for jcounter_idx in parse_button(button) {
+        for &jcounter_idx in button {
             *matrix.get_mut(jcounter_idx, c) = 1.;
         }
     }
@@ -415,20 +405,18 @@ fn bfs_matrix(matrix: Matrix<f64>, rhs: Matrix<f64>) -> u32 {
 }
 
 pub fn part2(input: &str) -> u64 {
+    min_joltage_presses(&parse(input).unwrap_or_else(|e| panic!("{e}")))
+}
+
+pub fn min_joltage_presses(machines: &[Machine]) -> u64 {
     let mut presses = 0;
     let mut joltages = vec![];
-    for (li, line) in input.lines().enumerate() {
-        let (_lights_str, line) = line.split_once(' ').unwrap();
-        let (buttons_str, joltage_str) = line.split_at(line.rfind(' ').unwrap());
-        let joltage_str = joltage_str.trim();
-
+    for (li, machine) in machines.iter().enumerate() {
         // Matrix approach
         joltages.clear();
-        for jolt in parse_joltage(joltage_str) {
-            joltages.push(jolt as i32);
-        }
+        joltages.extend_from_slice(&machine.joltage);
 
-        let ge = matrix_ge(buttons_str, &mut joltages);
+        let ge = matrix_ge(&machine.buttons, &mut joltages);
         let p = match ge {
             GaussElimResult::NoSolution => panic!("No solution found"),
             GaussElimResult::OneSolution(m) => {
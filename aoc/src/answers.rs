@@ -0,0 +1,129 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use aocutils::runner::Part;
+use toml::{Table, Value};
+
+/// Default file name of the answers, inside the input directory
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known correct answers, keyed by year, day and part:
+///
+/// ```toml
+/// [2024.16]
+/// part1 = 123
+/// part2 = "abc"
+/// ```
+///
+/// Answers depend on the input, so they are kept next to the inputs rather than in the tree.
+#[derive(Debug, Default)]
+pub struct Answers {
+    path: PathBuf,
+    table: Table,
+}
+
+impl Answers {
+    pub fn default_path(dir: &Path) -> PathBuf {
+        dir.join(ANSWERS_FILE)
+    }
+
+    /// Loads answers from `path`. A missing file has no answers, any other error is reported
+    /// so that [save][Self::save] never overwrites a file which could not be read.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let table = match std::fs::read_to_string(path) {
+            Ok(s) => s
+                .parse::<Table>()
+                .map_err(|e| format!("{}: {e}", path.display()))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Table::new(),
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            table,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<String> {
+        let value = self
+            .table
+            .get(&year.to_string())?
+            .get(day.to_string())?
+            .get(key(part))?;
+        Some(match value {
+            Value::String(s) => s.clone(),
+            v => v.to_string(),
+        })
+    }
+
+    /// Records an answer, stored as an integer when it is one
+    pub fn set(&mut self, year: u16, day: u8, part: Part, answer: &str) {
+        let value = match answer.parse::<i64>() {
+            Ok(n) => Value::Integer(n),
+            Err(_) => Value::String(answer.to_string()),
+        };
+        let year = self
+            .table
+            .entry(year.to_string())
+            .or_insert_with(|| Value::Table(Table::new()));
+        let Value::Table(year) = year else {
+            return;
+        };
+        if let Value::Table(day) = year
+            .entry(day.to_string())
+            .or_insert_with(|| Value::Table(Table::new()))
+        {
+            day.insert(key(part).to_string(), value);
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(&self.path, self.table.to_string())
+            .map_err(|e| format!("{}: {e}", self.path.display()))
+    }
+}
+
+fn key(part: Part) -> &'static str {
+    match part {
+        Part::One => "part1",
+        Part::Two => "part2",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_set() {
+        let mut answers = Answers {
+            path: PathBuf::new(),
+            table: "[2024.16]\npart1 = 123\npart2 = \"abc\"\n".parse().unwrap(),
+        };
+        assert_eq!(answers.get(2024, 16, Part::One).as_deref(), Some("123"));
+        assert_eq!(answers.get(2024, 16, Part::Two).as_deref(), Some("abc"));
+        assert_eq!(answers.get(2024, 17, Part::One), None);
+
+        answers.set(2024, 17, Part::Two, "9,8,7");
+        answers.set(2025, 1, Part::One, "42");
+        assert_eq!(answers.get(2024, 17, Part::Two).as_deref(), Some("9,8,7"));
+        assert_eq!(answers.get(2025, 1, Part::One).as_deref(), Some("42"));
+        assert!(answers.table.to_string().contains("part1 = 42"));
+    }
+
+    #[test]
+    fn load() {
+        let dir = std::env::temp_dir();
+        let missing = dir.join(format!("aoc-answers-{}.toml", std::process::id()));
+        assert!(Answers::load(&missing).unwrap().table.is_empty());
+        // Unreadable files are errors rather than empty answers
+        let err = Answers::load(&dir).unwrap_err();
+        assert!(err.starts_with(&dir.display().to_string()), "{err}");
+    }
+}
@@ -0,0 +1,43 @@
+use aocutils::bench::{self, BenchConfig, Pretty, Stats};
+use aocutils::runner::{Day, Part};
+
+fn row(name: &str, stats: &Stats) {
+    println!(
+        "  {name:<12} {:>11} {:>11} {:>11} {:>11}",
+        Pretty(stats.median),
+        Pretty(stats.mean),
+        Pretty(stats.stddev),
+        Pretty(stats.min)
+    );
+}
+
+/// Prints a table comparing the solution of a part with its registered variants.
+///
+/// Variants parse their own input, so they compare with the `parse+solve` row.
+pub fn bench_part(day: &Day, input: &str, part: Part, config: BenchConfig) {
+    let solution = match day.bench(input, part, config) {
+        Ok(Some(solution)) => solution,
+        Ok(None) => {
+            println!("{day} part {part}: unsolved");
+            return;
+        }
+        Err(e) => {
+            eprintln!("{day}: invalid input, {e}");
+            return;
+        }
+    };
+    println!("{day} part {part} ({} iterations)", solution.solve.samples);
+    println!(
+        "  {:<12} {:>11} {:>11} {:>11} {:>11}",
+        "", "median", "mean", "stddev", "min"
+    );
+    row("parse", &solution.parse);
+    row("solve", &solution.solve);
+    row("parse+solve", &solution.total);
+    for variant in day.variants_of(part) {
+        row(
+            variant.name,
+            &bench::bench(config, || (variant.solve)(input)),
+        );
+    }
+}
@@ -0,0 +1,491 @@
+use aocutils::bench::BenchConfig;
+use aocutils::reading::input::{INPUT_ENV, InputSource};
+use aocutils::runner::Part;
+use aocutils::runner::check::CheckConfig;
+
+pub const USAGE: &str = "Usage:
+    aoc list
+    aoc run <year> <day> [--part <1|2>] [--input <path>]
+    aoc run <year> --all [--part <1|2>]
+    aoc run --all [--part <1|2>]
+    aoc bench <year> <day> [--part <1|2>] [--input <path>] [-n <iterations>] [-w <warmup>]
+    aoc bench <year> --all [--part <1|2>] [-n <iterations>] [-w <warmup>]
+    aoc verify [<year> [<day>]] [--record] [--answers <path>]
+    aoc check [<year> [<day>]] [--input <path>] [--random <count>] [--seed <seed>]
+    aoc new <year> <day>
+    aoc fetch <year> <day> [--force]
+    aoc submit <year> <day> <part> <answer>
+
+Inputs are read from inputs/<year>/<day>.txt unless --input (or AOC_INPUT) is given,
+which only works for a single day.
+An input path of `-` reads from stdin.
+verify checks answers against inputs/answers.toml; --record saves answers missing from it.
+check runs every variant of a part against each other on the samples, the input and
+--random generated inputs, reporting the first input they disagree on.
+new creates <year>/day<day> from a template and registers it in the workspace and runner,
+taking the example and its answers from a puzzle page saved as inputs/<year>/<day>.html.
+fetch and submit use the AOC_SESSION cookie against AOC_BASE_URL (https://adventofcode.com).";
+
+/// Which registered days a command applies to
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Selection {
+    All,
+    Year(u16),
+    Day(u16, u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    List,
+    Run {
+        selection: Selection,
+        part: Option<Part>,
+        input: Option<String>,
+    },
+    Bench {
+        selection: Selection,
+        part: Option<Part>,
+        input: Option<String>,
+        config: BenchConfig,
+    },
+    Verify {
+        selection: Selection,
+        record: bool,
+        answers: Option<String>,
+    },
+    Check {
+        selection: Selection,
+        input: Option<String>,
+        config: CheckConfig,
+    },
+    New {
+        year: u16,
+        day: u8,
+    },
+    Fetch {
+        year: u16,
+        day: u8,
+        force: bool,
+    },
+    Submit {
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: String,
+    },
+}
+
+/// Arguments shared by commands operating on a [Selection]
+#[derive(Debug, PartialEq, Eq)]
+struct SelectionArgs {
+    selection: Selection,
+    part: Option<Part>,
+    input: Option<String>,
+}
+
+pub fn parse<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let Some(command) = args.next() else {
+        return Ok(Command::Help);
+    };
+    match command.as_str() {
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        "list" => Ok(Command::List),
+        "run" => {
+            let SelectionArgs {
+                selection,
+                part,
+                input,
+            } = parse_selection(args, false)?;
+            Ok(Command::Run {
+                selection,
+                part,
+                input,
+            })
+        }
+        "bench" => {
+            let mut config = BenchConfig::default();
+            let mut rest = vec![];
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--iterations" | "-n" => {
+                        config.iterations = parse_count(args.next(), "--iterations")?
+                    }
+                    "--warmup" | "-w" => config.warmup = parse_count(args.next(), "--warmup")?,
+                    _ => rest.push(arg),
+                }
+            }
+            let SelectionArgs {
+                selection,
+                part,
+                input,
+            } = parse_selection(rest.into_iter(), false)?;
+            Ok(Command::Bench {
+                selection,
+                part,
+                input,
+                config,
+            })
+        }
+        "verify" => {
+            let mut record = false;
+            let mut answers = None;
+            let mut rest = vec![];
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--record" | "-r" => record = true,
+                    "--answers" => {
+                        answers = Some(args.next().ok_or("missing value for --answers")?)
+                    }
+                    _ => rest.push(arg),
+                }
+            }
+            let SelectionArgs {
+                selection,
+                part,
+                input,
+            } = parse_selection(rest.into_iter(), true)?;
+            if part.is_some() || input.is_some() {
+                return Err("verify always checks both parts against the cached input".to_string());
+            }
+            Ok(Command::Verify {
+                selection,
+                record,
+                answers,
+            })
+        }
+        "check" => {
+            let mut config = CheckConfig::default();
+            let mut rest = vec![];
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--random" | "-r" => config.random = parse_count(args.next(), "--random")?,
+                    "--seed" => {
+                        let seed = args.next().ok_or("missing value for --seed")?;
+                        config.seed = seed
+                            .parse()
+                            .map_err(|_| format!("invalid value `{seed}` for --seed"))?;
+                    }
+                    _ => rest.push(arg),
+                }
+            }
+            let SelectionArgs {
+                selection,
+                part,
+                input,
+            } = parse_selection(rest.into_iter(), true)?;
+            if part.is_some() {
+                return Err("check always compares both parts".to_string());
+            }
+            Ok(Command::Check {
+                selection,
+                input,
+                config,
+            })
+        }
+        "new" => {
+            let [year, day] = args
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| "expected a year and day".to_string())?;
+            let day = parse_day(&day)?;
+            if !(1..=25).contains(&day) {
+                return Err(format!("day {day} is not between 1 and 25"));
+            }
+            Ok(Command::New {
+                year: parse_year(&year)?,
+                day,
+            })
+        }
+        "fetch" => {
+            let mut force = false;
+            let mut positional = vec![];
+            for arg in args {
+                match arg.as_str() {
+                    "--force" | "-f" => force = true,
+                    _ if arg.starts_with('-') => return Err(format!("unknown flag `{arg}`")),
+                    _ => positional.push(arg),
+                }
+            }
+            let [year, day] = positional
+                .try_into()
+                .map_err(|_| "expected a year and day".to_string())?;
+            Ok(Command::Fetch {
+                year: parse_year(&year)?,
+                day: parse_day(&day)?,
+                force,
+            })
+        }
+        "submit" => {
+            let [year, day, part, answer] = args
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| "expected a year, day, part and answer".to_string())?;
+            Ok(Command::Submit {
+                year: parse_year(&year)?,
+                day: parse_day(&day)?,
+                part: parse_part(&part)?,
+                answer,
+            })
+        }
+        _ => Err(format!("unknown command `{command}`")),
+    }
+}
+
+fn parse_count(s: Option<String>, flag: &str) -> Result<usize, String> {
+    let s = s.ok_or_else(|| format!("missing value for {flag}"))?;
+    s.parse()
+        .map_err(|_| format!("invalid value `{s}` for {flag}"))
+}
+
+fn parse_year(s: &str) -> Result<u16, String> {
+    s.parse().map_err(|_| format!("invalid year `{s}`"))
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    s.parse().map_err(|_| format!("invalid day `{s}`"))
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    s.parse()
+        .ok()
+        .and_then(Part::from_num)
+        .ok_or_else(|| format!("invalid part `{s}`"))
+}
+
+/// Parses `[<year> [<day>]] [--all]`.
+/// With `implicit_all`, a missing day (or year) selects everything without needing `--all`.
+fn parse_selection<I>(mut args: I, implicit_all: bool) -> Result<SelectionArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut positional = vec![];
+    let mut part = None;
+    let mut input = None;
+    let mut all = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" | "-a" => all = true,
+            "--part" | "-p" => {
+                part = Some(parse_part(&args.next().ok_or("missing value for --part")?)?);
+            }
+            "--input" | "-i" => input = Some(args.next().ok_or("missing value for --input")?),
+            _ if arg.starts_with('-') => return Err(format!("unknown flag `{arg}`")),
+            _ => positional.push(arg),
+        }
+    }
+
+    let year = positional.first().map(|y| parse_year(y)).transpose()?;
+    let day = positional.get(1).map(|d| parse_day(d)).transpose()?;
+    if positional.len() > 2 {
+        return Err(format!("unexpected argument `{}`", positional[2]));
+    }
+
+    let selection = match (year, day, all || implicit_all) {
+        (None, None, true) => Selection::All,
+        (Some(year), None, true) => Selection::Year(year),
+        (Some(year), Some(day), _) if !all => Selection::Day(year, day),
+        (_, Some(_), true) => return Err("--all cannot be combined with a day".to_string()),
+        _ => return Err("expected a year and day, or --all".to_string()),
+    };
+    if input.is_some() && !matches!(selection, Selection::Day(..)) {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    Ok(SelectionArgs {
+        selection,
+        part,
+        input,
+    })
+}
+
+/// Where to read inputs from, given `--input` and the value of [INPUT_ENV].
+/// Like `--input`, the environment variable names the input of a single day.
+pub fn input_source(
+    selection: Selection,
+    input: Option<&str>,
+    env: Option<&str>,
+) -> Result<InputSource, String> {
+    match (input, env.filter(|arg| !arg.is_empty())) {
+        (Some(arg), _) => Ok(InputSource::from_arg(arg)),
+        (None, Some(arg)) if matches!(selection, Selection::Day(..)) => {
+            Ok(InputSource::from_arg(arg))
+        }
+        (None, Some(_)) => Err(format!(
+            "{INPUT_ENV} can only be used with a single day, unset it to select several"
+        )),
+        (None, None) => Ok(InputSource::Cache),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> {
+        s.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn run() {
+        assert_eq!(
+            parse(args("run 2024 16 --part 2")),
+            Ok(Command::Run {
+                selection: Selection::Day(2024, 16),
+                part: Some(Part::Two),
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse(args("run 2025 --all")),
+            Ok(Command::Run {
+                selection: Selection::Year(2025),
+                part: None,
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse(args("run --all -p 1")),
+            Ok(Command::Run {
+                selection: Selection::All,
+                part: Some(Part::One),
+                input: None,
+            })
+        );
+        assert_eq!(
+            parse(args("run 2024 1 --input -")),
+            Ok(Command::Run {
+                selection: Selection::Day(2024, 1),
+                part: None,
+                input: Some("-".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn fetch_submit() {
+        assert_eq!(
+            parse(args("fetch 2025 10 --force")),
+            Ok(Command::Fetch {
+                year: 2025,
+                day: 10,
+                force: true
+            })
+        );
+        assert_eq!(
+            parse(args("submit 2025 10 2 1234")),
+            Ok(Command::Submit {
+                year: 2025,
+                day: 10,
+                part: Part::Two,
+                answer: "1234".to_string()
+            })
+        );
+        assert!(parse(args("fetch 2025")).is_err());
+        assert_eq!(
+            parse(args("new 2025 13")),
+            Ok(Command::New {
+                year: 2025,
+                day: 13
+            })
+        );
+        assert!(parse(args("new 2025 26")).is_err());
+        assert!(parse(args("submit 2025 10 3 1234")).is_err());
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse(args("bench 2024 7 -p 1 -n 50 --warmup 0")),
+            Ok(Command::Bench {
+                selection: Selection::Day(2024, 7),
+                part: Some(Part::One),
+                input: None,
+                config: BenchConfig {
+                    warmup: 0,
+                    iterations: 50
+                }
+            })
+        );
+        assert!(parse(args("bench 2024 7 -n")).is_err());
+        assert!(parse(args("bench 2024 7 -n x")).is_err());
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(
+            parse(args("verify")),
+            Ok(Command::Verify {
+                selection: Selection::All,
+                record: false,
+                answers: None
+            })
+        );
+        assert_eq!(
+            parse(args("verify 2024 --record --answers a.toml")),
+            Ok(Command::Verify {
+                selection: Selection::Year(2024),
+                record: true,
+                answers: Some("a.toml".to_string())
+            })
+        );
+        assert!(parse(args("verify 2024 1 --part 1")).is_err());
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(
+            parse(args("check 2024 7 --random 100 --seed 3 -i a.txt")),
+            Ok(Command::Check {
+                selection: Selection::Day(2024, 7),
+                input: Some("a.txt".to_string()),
+                config: CheckConfig {
+                    random: 100,
+                    seed: 3
+                }
+            })
+        );
+        assert_eq!(
+            parse(args("check")),
+            Ok(Command::Check {
+                selection: Selection::All,
+                input: None,
+                config: CheckConfig::default()
+            })
+        );
+        assert!(parse(args("check 2024 7 -p 1")).is_err());
+        assert!(parse(args("check 2024 7 --seed x")).is_err());
+    }
+
+    #[test]
+    fn run_invalid() {
+        assert!(parse(args("run 2025")).is_err());
+        assert!(parse(args("run 2025 1 --all")).is_err());
+        assert!(parse(args("run 2025 1 --part 3")).is_err());
+        assert!(parse(args("run 2025 x")).is_err());
+        assert!(parse(args("run 2025 --all --input a.txt")).is_err());
+        assert!(parse(args("frobnicate")).is_err());
+    }
+
+    #[test]
+    fn input_env() {
+        let day = Selection::Day(2024, 1);
+        assert_eq!(
+            input_source(day, None, Some("a.txt")),
+            Ok(InputSource::from_arg("a.txt"))
+        );
+        assert_eq!(
+            input_source(day, Some("-"), Some("a.txt")),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(input_source(day, None, Some("")), Ok(InputSource::Cache));
+        assert!(input_source(Selection::All, None, Some("a.txt")).is_err());
+        assert!(input_source(Selection::Year(2024), None, Some("-")).is_err());
+        assert_eq!(
+            input_source(Selection::All, None, None),
+            Ok(InputSource::Cache)
+        );
+    }
+}
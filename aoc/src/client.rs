@@ -0,0 +1,313 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aocutils::reading::input;
+use aocutils::runner::Part;
+
+/// Overrides [DEFAULT_BASE_URL], e.g. to point at a local stand-in server
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Session cookie value. Falls back to the contents of [SESSION_FILE] in the input directory.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const SESSION_FILE: &str = ".session";
+
+/// Minimum number of seconds between two requests, across invocations
+pub const RATE_LIMIT_ENV: &str = "AOC_RATE_LIMIT_SECS";
+pub const DEFAULT_RATE_LIMIT: Duration = Duration::from_secs(5);
+
+/// Timestamp of the last request, stored in the input directory
+const LAST_REQUEST_FILE: &str = ".last-request";
+
+const USER_AGENT: &str = "github.com/sbxte/aoc-rs";
+
+/// Server response to a submitted answer
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after a previous answer, with the wait if the server gave one
+    RateLimited(Option<Duration>),
+    /// The part was already solved or is not unlocked yet
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Whether the verdict says something about the answer itself
+    pub fn is_final(self) -> bool {
+        !matches!(self, Self::RateLimited(_) | Self::WrongLevel)
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+
+    /// Name used in the submission log
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::RateLimited(_) => "rate-limited",
+            Self::WrongLevel => "wrong-level",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "correct" => Self::Correct,
+            "wrong" => Self::Wrong,
+            "too-high" => Self::TooHigh,
+            "too-low" => Self::TooLow,
+            "rate-limited" => Self::RateLimited(None),
+            "wrong-level" => Self::WrongLevel,
+            _ => return None,
+        })
+    }
+
+    /// Reads the verdict out of the HTML page returned after submitting
+    pub fn from_response(body: &str) -> Option<Self> {
+        if body.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if body.contains("That's not the right answer") {
+            Some(if body.contains("your answer is too high") {
+                Self::TooHigh
+            } else if body.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            })
+        } else if body.contains("You gave an answer too recently") {
+            Some(Self::RateLimited(parse_wait(body)))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the `You have 1m 23s left to wait.` hint of a rate-limited submission
+fn parse_wait(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for unit in body[start..end].split_whitespace() {
+        let (n, mul) = if let Some(m) = unit.strip_suffix('m') {
+            (m, 60)
+        } else {
+            (unit.strip_suffix('s')?, 1)
+        };
+        secs += n.parse::<u64>().ok()? * mul;
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    rate_limit: Duration,
+    /// Directory holding the cached inputs and client state
+    dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, rate_limit: Duration, dir: &Path) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            rate_limit,
+            dir: dir.to_path_buf(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Configures a client from the environment, see [BASE_URL_ENV], [SESSION_ENV] and
+    /// [RATE_LIMIT_ENV]
+    pub fn from_env() -> Result<Self, String> {
+        let dir = input::input_dir();
+        let base_url = std::env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = match std::env::var(SESSION_ENV) {
+            Ok(session) => session,
+            Err(_) => std::fs::read_to_string(dir.join(SESSION_FILE)).map_err(|_| {
+                format!(
+                    "no session cookie; set {SESSION_ENV} or save it to {}",
+                    dir.join(SESSION_FILE).display()
+                )
+            })?,
+        };
+        let rate_limit = match std::env::var(RATE_LIMIT_ENV) {
+            Ok(secs) => Duration::from_secs_f64(
+                secs.parse()
+                    .map_err(|_| format!("invalid {RATE_LIMIT_ENV} `{secs}`"))?,
+            ),
+            Err(_) => DEFAULT_RATE_LIMIT,
+        };
+        Ok(Self::new(&base_url, &session, rate_limit, &dir))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the cached input of a puzzle, downloading it first if needed
+    pub fn fetch_input(&self, year: u16, day: u8, force: bool) -> Result<String, String> {
+        let path = input::cache_path(&self.dir, year, day);
+        if !force && let Ok(input) = std::fs::read_to_string(&path) {
+            return Ok(input);
+        }
+
+        self.throttle();
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let input = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| format!("GET {url}: {e}"))?
+            .into_string()
+            .map_err(|e| format!("GET {url}: {e}"))?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(&path, &input).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(input)
+    }
+
+    /// Posts an answer and returns the server's verdict
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, String> {
+        self.throttle();
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let body = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| format!("POST {url}: {e}"))?
+            .into_string()
+            .map_err(|e| format!("POST {url}: {e}"))?;
+        Verdict::from_response(&body).ok_or_else(|| format!("POST {url}: unrecognized response"))
+    }
+
+    /// Sleeps until the rate limit has passed since the last request
+    fn throttle(&self) {
+        let path = self.dir.join(LAST_REQUEST_FILE);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let last = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(wait) = last.and_then(|last| (last + self.rate_limit).checked_sub(now)) {
+            std::thread::sleep(wait);
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let _ = std::fs::create_dir_all(&self.dir);
+        let _ = std::fs::write(path, now.as_millis().to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(
+            Verdict::from_response("<p>That's the right answer! You are one gold star closer"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "<p>That's not the right answer; your answer is too high.  If you're stuck"
+            ),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response("<p>That's not the right answer.  If you're stuck"),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "<p>You gave an answer too recently; You have 1m 5s left to wait."
+            ),
+            Some(Verdict::RateLimited(Some(Duration::from_secs(65))))
+        );
+        assert_eq!(Verdict::from_response("<html></html>"), None);
+        assert_eq!(
+            Verdict::from_name(Verdict::TooLow.name()),
+            Some(Verdict::TooLow)
+        );
+    }
+
+    /// Serves a single request, returning `body`
+    fn stand_in(body: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut len = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(l) = line.to_lowercase().strip_prefix("content-length:") {
+                    len = l.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; len];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn stand_in_server() {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let (url, server) = stand_in("1 2 3\n");
+        let client = Client::new(&url, "abc", Duration::ZERO, &dir);
+        assert_eq!(client.fetch_input(2025, 10, false).unwrap(), "1 2 3\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/10/input"));
+        assert!(request.contains("session=abc"));
+        // Served from the cache without a server
+        assert_eq!(client.fetch_input(2025, 10, false).unwrap(), "1 2 3\n");
+
+        let (url, server) = stand_in("<p>That's not the right answer; your answer is too low.");
+        let client = Client::new(&url, "abc", Duration::ZERO, &dir);
+        assert_eq!(
+            client.submit(2025, 10, Part::Two, "42"),
+            Ok(Verdict::TooLow)
+        );
+        assert!(server.join().unwrap().ends_with("level=2&answer=42"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
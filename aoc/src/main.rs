@@ -0,0 +1,287 @@
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+
+use aocutils::bench::BenchConfig;
+use aocutils::reading::input::{self, INPUT_ENV, InputError};
+use aocutils::reading::puzzle::{self, Puzzle};
+use aocutils::runner::check::CheckConfig;
+use aocutils::runner::{self, Day, Part};
+
+use answers::Answers;
+use cli::{Command, Selection};
+use client::{Client, Verdict};
+use submissions::{Submission, Submissions};
+
+mod answers;
+mod bench;
+mod cli;
+mod client;
+mod registry;
+mod scaffold;
+mod submissions;
+mod verify;
+
+fn select(selection: Selection) -> Vec<&'static Day> {
+    match selection {
+        Selection::All => registry::DAYS.iter().collect(),
+        Selection::Year(year) => registry::of_year(year).collect(),
+        Selection::Day(year, day) => registry::find(year, day).into_iter().collect(),
+    }
+}
+
+fn run(selection: Selection, part: Option<Part>, input: Option<&str>) -> Result<(), String> {
+    let days = select(selection);
+    if days.is_empty() {
+        return Err("no registered solutions match".to_string());
+    }
+    let source = cli::input_source(selection, input, env::var(INPUT_ENV).ok().as_deref())?;
+    for day in days {
+        let input = match day.load_input(&source) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{day}: {e}");
+                continue;
+            }
+        };
+        let printed = match part {
+            Some(part) => runner::print_day(day, &input, std::iter::once(part)),
+            None => runner::print_day(day, &input, Part::iter_all()),
+        };
+        if let Err(e) = printed {
+            eprintln!("{day}: invalid input, {e}");
+        }
+    }
+    Ok(())
+}
+
+fn bench(
+    selection: Selection,
+    part: Option<Part>,
+    input: Option<&str>,
+    config: BenchConfig,
+) -> Result<(), String> {
+    let days = select(selection);
+    if days.is_empty() {
+        return Err("no registered solutions match".to_string());
+    }
+    let source = cli::input_source(selection, input, env::var(INPUT_ENV).ok().as_deref())?;
+    for day in days {
+        let input = match day.load_input(&source) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{day}: {e}");
+                continue;
+            }
+        };
+        for p in Part::iter_all().filter(|p| part.is_none_or(|part| part == *p)) {
+            bench::bench_part(day, &input, p, config);
+        }
+    }
+    Ok(())
+}
+
+fn verify(selection: Selection, record: bool, answers: Option<&str>) -> Result<(), String> {
+    let path = answers
+        .map(Into::into)
+        .unwrap_or_else(|| Answers::default_path(&input::input_dir()));
+    let mut answers = Answers::load(&path)?;
+    let reports = verify::verify(&select(selection), &answers);
+
+    for report in &reports {
+        let day = report.day;
+        match &report.parts {
+            Ok(parts) => {
+                for (part, outcome) in parts {
+                    println!("{day} part {part}: {outcome}");
+                    if let (true, verify::Outcome::Missing { actual }) = (record, outcome) {
+                        answers.set(day.year, day.day, *part, actual);
+                    }
+                }
+            }
+            Err(e) => println!("{day}: skipped, {e}"),
+        }
+    }
+
+    let summary = verify::Summary::from_reports(&reports);
+    println!("\n{summary}");
+    if record && summary.missing > 0 {
+        answers.save()?;
+        println!(
+            "recorded {} answers in {}",
+            summary.missing,
+            answers.path().display()
+        );
+    }
+    if summary.failed() {
+        return Err("verification failed".to_string());
+    }
+    Ok(())
+}
+
+fn check(selection: Selection, input: Option<&str>, config: CheckConfig) -> Result<(), String> {
+    let single = matches!(selection, Selection::Day(..));
+    let days: Vec<_> = select(selection)
+        .into_iter()
+        .filter(|day| single || !day.variants.is_empty())
+        .collect();
+    if days.is_empty() {
+        return Err("no registered solutions with variants match".to_string());
+    }
+    let source = cli::input_source(selection, input, env::var(INPUT_ENV).ok().as_deref())?;
+    let mut failed = false;
+    // Panics are reported per day instead of being printed as they happen
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    for day in days {
+        if day.variants.is_empty() {
+            println!("{day}: no variants to check");
+            continue;
+        }
+        let input = match day.load_input(&source) {
+            Ok(input) => Some(input),
+            Err(InputError::Missing { .. }) => None,
+            Err(e) => {
+                println!("{day}: skipped, {e}");
+                continue;
+            }
+        };
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            day.cross_check(input.as_deref(), config)
+        }));
+        let skipped = if input.is_none() { ", no input" } else { "" };
+        match result {
+            Ok(Ok(checked)) => println!("{day}: ok, {checked} inputs agree{skipped}"),
+            Ok(Err(divergence)) => {
+                failed = true;
+                println!("{day}: {divergence}");
+            }
+            Err(payload) => {
+                failed = true;
+                println!("{day}: PANIC {}", verify::panic_message(payload));
+            }
+        }
+    }
+    panic::set_hook(hook);
+    if failed {
+        return Err("variants disagree".to_string());
+    }
+    Ok(())
+}
+
+fn new(year: u16, day: u8) -> Result<(), String> {
+    let root = scaffold::workspace_root();
+    let puzzle = puzzle::puzzle_path(&input::input_dir(), year, day);
+    let template = match Puzzle::load(&puzzle) {
+        Ok(page) => {
+            println!("using the example from {}", puzzle.display());
+            scaffold::Template::from_puzzle(&page)
+        }
+        Err(_) => scaffold::Template::default(),
+    };
+    let krate = scaffold::new_day(&root, year, day, &template)?;
+    println!(
+        "created {} in {}",
+        krate.name,
+        root.join(&krate.dir).display()
+    );
+    if template == scaffold::Template::default() {
+        println!("no example found, fill in SAMPLE and the expected sample answers");
+    }
+    println!("run with:");
+    println!("    cargo run -p aoc -- fetch {year} {day}");
+    println!("    cargo run -p aoc -- run {year} {day}");
+    Ok(())
+}
+
+fn fetch(year: u16, day: u8, force: bool) -> Result<(), String> {
+    let client = Client::from_env()?;
+    let input = client.fetch_input(year, day, force)?;
+    println!(
+        "{year} day {day}: {} lines in {}",
+        input.lines().count(),
+        aocutils::reading::input::cache_path(client.dir(), year, day).display()
+    );
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: Part, answer: &str) -> Result<(), String> {
+    let client = Client::from_env()?;
+    let mut submissions = Submissions::load(client.dir(), year, day)?;
+    submissions
+        .check(part, answer)
+        .map_err(|e| format!("refusing to submit: {e}"))?;
+
+    let verdict = client.submit(year, day, part, answer)?;
+    submissions.record(Submission {
+        part,
+        answer: answer.to_string(),
+        verdict,
+    })?;
+    match verdict {
+        Verdict::Correct => {
+            println!("{year} day {day} part {part}: {answer} is correct");
+            let mut answers = Answers::load(&Answers::default_path(client.dir()))?;
+            answers.set(year, day, part, answer);
+            answers.save()?;
+        }
+        Verdict::RateLimited(Some(wait)) => {
+            println!("rate limited, try again in {}s", wait.as_secs())
+        }
+        Verdict::RateLimited(None) => println!("rate limited, try again later"),
+        Verdict::WrongLevel => println!("part {part} is already solved or not unlocked yet"),
+        _ => println!(
+            "{year} day {day} part {part}: {answer} is {}",
+            verdict.name()
+        ),
+    }
+    Ok(())
+}
+
+fn main() {
+    let result = match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        Ok(Command::List) => {
+            for day in registry::DAYS {
+                println!("{day}");
+            }
+            Ok(())
+        }
+        Ok(Command::Run {
+            selection,
+            part,
+            input,
+        }) => run(selection, part, input.as_deref()),
+        Ok(Command::Bench {
+            selection,
+            part,
+            input,
+            config,
+        }) => bench(selection, part, input.as_deref(), config),
+        Ok(Command::Verify {
+            selection,
+            record,
+            answers,
+        }) => verify(selection, record, answers.as_deref()),
+        Ok(Command::Check {
+            selection,
+            input,
+            config,
+        }) => check(selection, input.as_deref(), config),
+        Ok(Command::New { year, day }) => new(year, day),
+        Ok(Command::Fetch { year, day, force }) => fetch(year, day, force),
+        Ok(Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        }) => submit(year, day, part, &answer),
+        Err(e) => Err(format!("{e}\n\n{}", cli::USAGE)),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        std::process::exit(1);
+    }
+}
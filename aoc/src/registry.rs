@@ -0,0 +1,56 @@
+use aocutils::runner::Day;
+
+/// Every solution known to the runner, ordered by year and day
+pub const DAYS: &[Day] = &[
+    aoc2022d8::DAY,
+    aoc2022d9::DAY,
+    aoc2022d13::DAY,
+    aoc2022d14::DAY,
+    aoc2022d15::DAY,
+    aoc2022d16::DAY,
+    aoc2024d1::DAY,
+    aoc2024d2::DAY,
+    aoc2024d3::DAY,
+    aoc2024d4::DAY,
+    aoc2024d5::DAY,
+    aoc2024d6::DAY,
+    aoc2024d7::DAY,
+    aoc2024d8::DAY,
+    aoc2024d9::DAY,
+    aoc2024d10::DAY,
+    aoc2024d11::DAY,
+    aoc2024d12::DAY,
+    aoc2024d13::DAY,
+    aoc2024d14::DAY,
+    aoc2024d15::DAY,
+    aoc2024d16::DAY,
+    aoc2024d17::DAY,
+    aoc2024d18::DAY,
+    aoc2024d19::DAY,
+    aoc2024d20::DAY,
+    aoc2024d21::DAY,
+    aoc2024d22::DAY,
+    aoc2024d23::DAY,
+    aoc2024d24::DAY,
+    aoc2024d25::DAY,
+    aoc2025d1::DAY,
+    aoc2025d02::DAY,
+    aoc2025d03::DAY,
+    aoc2025d04::DAY,
+    aoc2025d05lib::DAY,
+    aoc2025d06::DAY,
+    aoc2025d07::DAY,
+    aoc2025d08::DAY,
+    aoc2025d09::DAY,
+    aoc2025d10::DAY,
+    aoc2025d11::DAY,
+    aoc2025d12::DAY,
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
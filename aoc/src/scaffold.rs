@@ -0,0 +1,295 @@
+use std::path::{Path, PathBuf};
+
+use aocutils::reading::puzzle::Puzzle;
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml.tmpl");
+const MAIN_RS: &str = include_str!("../templates/day/main.rs.tmpl");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs.tmpl");
+const P1_RS: &str = include_str!("../templates/day/p1.rs.tmpl");
+const P2_RS: &str = include_str!("../templates/day/p2.rs.tmpl");
+
+/// Root of the workspace this binary was built from
+pub fn workspace_root() -> PathBuf {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    manifest.parent().unwrap_or(manifest).to_path_buf()
+}
+
+/// Names of a day's crate, following the convention of the existing days of its year
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DayCrate {
+    pub year: u16,
+    pub day: u8,
+    /// Directory relative to the workspace root, e.g. `2025/day13`
+    pub dir: String,
+    /// Package name, e.g. `aoc2025d13`
+    pub name: String,
+}
+
+impl DayCrate {
+    /// Days are zero padded (`day01`) unless the year already uses unpadded names (`day1`)
+    pub fn new(root: &Path, year: u16, day: u8) -> Self {
+        let unpadded = std::fs::read_dir(root.join(year.to_string()))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter_map(|name| name.strip_prefix("day").map(str::to_string))
+            .any(|n| n.len() == 1);
+        let suffix = if unpadded {
+            day.to_string()
+        } else {
+            format!("{day:02}")
+        };
+        Self {
+            year,
+            day,
+            dir: format!("{year}/day{suffix}"),
+            name: format!("aoc{year}d{suffix}"),
+        }
+    }
+
+    /// Name of the [Solution][aocutils::runner::Solution] implementing struct, e.g. `Day13`
+    pub fn struct_name(&self) -> String {
+        format!("Day{}", self.day)
+    }
+}
+
+/// Values substituted into the templates
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Template {
+    /// Contents of `SAMPLE`, already escaped for a string literal
+    pub sample: String,
+    /// Expected answers of the sample tests
+    pub answers: [String; 2],
+}
+
+impl Default for Template {
+    fn default() -> Self {
+        Self {
+            sample: String::new(),
+            answers: ["0".to_string(), "0".to_string()],
+        }
+    }
+}
+
+impl Template {
+    /// Fills the sample and its answers from a saved puzzle page where available
+    pub fn from_puzzle(puzzle: &Puzzle) -> Self {
+        let mut template = Self::default();
+        let samples = puzzle.samples();
+        if let Some((sample, _)) = samples.iter().flatten().next() {
+            template.sample = escape(sample.trim_end_matches('\n'));
+        }
+        for (slot, sample) in template.answers.iter_mut().zip(samples) {
+            if let Some((_, answer)) = sample.filter(|(_, a)| a.parse::<u64>().is_ok()) {
+                *slot = answer.to_string();
+            }
+        }
+        template
+    }
+
+    fn render(&self, template: &str, krate: &DayCrate) -> String {
+        template
+            .replace("{crate}", &krate.name)
+            .replace("{name}", &krate.struct_name())
+            .replace("{year}", &krate.year.to_string())
+            .replace("{day}", &krate.day.to_string())
+            .replace("{sample}", &self.sample)
+            .replace("{answer1}", &self.answers[0])
+            .replace("{answer2}", &self.answers[1])
+    }
+}
+
+/// Creates the crate of a new day and registers it in the workspace, the `aoc` crate's
+/// dependencies and the [registry][crate::registry]. Returns the created crate.
+pub fn new_day(root: &Path, year: u16, day: u8, template: &Template) -> Result<DayCrate, String> {
+    let krate = DayCrate::new(root, year, day);
+    let dir = root.join(&krate.dir);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Prepare every registration first so nothing is written if one of them fails
+    let key = (year, day);
+    let edits = [
+        ("Cargo.toml", format!("\"{}\",", krate.dir)),
+        (
+            "aoc/Cargo.toml",
+            format!("{} = {{ path = \"../{}\" }}", krate.name, krate.dir),
+        ),
+        ("aoc/src/registry.rs", format!("{}::DAY,", krate.name)),
+    ]
+    .into_iter()
+    .map(|(file, entry)| {
+        let path = root.join(file);
+        let contents =
+            std::fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let edited =
+            insert_entry(&contents, key, &entry).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok((path, edited))
+    })
+    .collect::<Result<Vec<_>, String>>()?;
+
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src).map_err(|e| format!("{}: {e}", src.display()))?;
+    for (path, contents) in [
+        (dir.join("Cargo.toml"), CARGO_TOML),
+        (src.join("main.rs"), MAIN_RS),
+        (src.join("lib.rs"), LIB_RS),
+        (src.join("p1.rs"), P1_RS),
+        (src.join("p2.rs"), P2_RS),
+    ] {
+        write(&path, &template.render(contents, &krate))?;
+    }
+    for (path, contents) in edits {
+        write(&path, &contents)?;
+    }
+    Ok(krate)
+}
+
+/// Escapes `s` for use inside a string literal, keeping newlines as they are
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Year and day of a line naming a day, either by directory (`"2025/day01",`)
+/// or by crate (`aoc2025d1 = ...`, `aoc2025d05lib::DAY,`)
+fn entry_key(line: &str) -> Option<(u16, u8)> {
+    let line = line.trim().trim_start_matches('"');
+    if let Some(name) = line.strip_prefix("aoc") {
+        let (year, rest) = name.split_once('d')?;
+        // Crate names may have a suffix after the day
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        return Some((year.parse().ok()?, rest[..digits].parse().ok()?));
+    }
+    let (year, rest) = line.split_once("/day")?;
+    let day = rest.split('"').next()?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Inserts `entry` after the last line whose [entry_key] precedes `key`, matching its
+/// indentation. Lists in these files are not strictly ordered, so only the preceding
+/// entries are considered.
+fn insert_entry(contents: &str, key: (u16, u8), entry: &str) -> Result<String, String> {
+    let lines: Vec<_> = contents.lines().collect();
+    let keyed = || {
+        lines
+            .iter()
+            .enumerate()
+            .filter_map(|(i, l)| Some((i, entry_key(l)?)))
+    };
+    if keyed().any(|(_, k)| k == key) {
+        return Err(format!("{} day {} is already listed", key.0, key.1));
+    }
+    let (at, indent) = match keyed().rfind(|&(_, k)| k < key) {
+        Some((i, _)) => (i + 1, i),
+        None => match keyed().next() {
+            Some((i, _)) => (i, i),
+            None => return Err("no days are listed".to_string()),
+        },
+    };
+    let indent = &lines[indent][..lines[indent].len() - lines[indent].trim_start().len()];
+
+    let mut out: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    out.insert(at, format!("{indent}{entry}"));
+    let mut out = out.join("\n");
+    if contents.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys() {
+        assert_eq!(entry_key("\t\"2025/day01\","), Some((2025, 1)));
+        assert_eq!(entry_key("\t\"2025/day07\", "), Some((2025, 7)));
+        assert_eq!(
+            entry_key("aoc2024d10 = { path = \"../2024/day10\" }"),
+            Some((2024, 10))
+        );
+        assert_eq!(entry_key("    aoc2022d8::DAY,"), Some((2022, 8)));
+        assert_eq!(entry_key("    aoc2025d05lib::DAY,"), Some((2025, 5)));
+        assert_eq!(
+            entry_key("aoc2025d05 = { path = \"../2025/day05\" }"),
+            Some((2025, 5))
+        );
+        assert_eq!(entry_key("aocutils = { path = \"../utils\" }"), None);
+        assert_eq!(entry_key("\t\"utils\","), None);
+    }
+
+    #[test]
+    fn insert() {
+        let members =
+            "members = [\n\t\"utils\",\n\t\"2024/day1\",\n\t\"2022/day8\",\n\t\"2025/day12\",\n]\n";
+        assert_eq!(
+            insert_entry(members, (2025, 13), "\"2025/day13\",").unwrap(),
+            "members = [\n\t\"utils\",\n\t\"2024/day1\",\n\t\"2022/day8\",\n\t\"2025/day12\",\n\t\"2025/day13\",\n]\n"
+        );
+        assert_eq!(
+            insert_entry(members, (2023, 1), "\"2023/day1\",").unwrap(),
+            "members = [\n\t\"utils\",\n\t\"2024/day1\",\n\t\"2022/day8\",\n\t\"2023/day1\",\n\t\"2025/day12\",\n]\n"
+        );
+        assert!(insert_entry(members, (2025, 12), "\"2025/day12\",").is_err());
+    }
+
+    #[test]
+    fn from_puzzle() {
+        let puzzle = Puzzle::parse(
+            "<article><pre><code>a \\ &quot;b&quot;\n1\n</code></pre><code><em>42</em></code></article>\
+             <article><code><em>x,y</em></code></article>",
+        );
+        let template = Template::from_puzzle(&puzzle);
+        assert_eq!(template.sample, "a \\\\ \\\"b\\\"\n1");
+        assert_eq!(template.answers, ["42".to_string(), "0".to_string()]);
+    }
+
+    #[test]
+    fn scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("aoc/src")).unwrap();
+        std::fs::create_dir_all(root.join("2024/day1")).unwrap();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n\t\"aoc\",\n\t\"2024/day1\",\n]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\naoc2024d1 = { path = \"../2024/day1\" }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("aoc/src/registry.rs"),
+            "pub const DAYS: &[Day] = &[\n    aoc2024d1::DAY,\n];\n",
+        )
+        .unwrap();
+
+        let padded = new_day(&root, 2025, 3, &Template::default()).unwrap();
+        assert_eq!(padded.dir, "2025/day03");
+        let unpadded = new_day(&root, 2024, 2, &Template::default()).unwrap();
+        assert_eq!(unpadded.name, "aoc2024d2");
+        assert!(new_day(&root, 2024, 2, &Template::default()).is_err());
+
+        let lib = std::fs::read_to_string(root.join("2025/day03/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub const DAY: Day = aocutils::day!(2025, 3, Day3);"));
+        let registry = std::fs::read_to_string(root.join("aoc/src/registry.rs")).unwrap();
+        assert_eq!(
+            registry,
+            "pub const DAYS: &[Day] = &[\n    aoc2024d1::DAY,\n    aoc2024d2::DAY,\n    aoc2025d03::DAY,\n];\n"
+        );
+        let members = std::fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(members.ends_with("\t\"2024/day2\",\n\t\"2025/day03\",\n]\n"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,142 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use aocutils::runner::Part;
+
+use crate::client::Verdict;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Log of the answers submitted for one puzzle.
+///
+/// Stored next to the cached input as `<year>/<day>.submissions`, one `part verdict answer` line
+/// per submission.
+#[derive(Debug)]
+pub struct Submissions {
+    path: PathBuf,
+    pub entries: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn path(dir: &Path, year: u16, day: u8) -> PathBuf {
+        dir.join(year.to_string())
+            .join(format!("{day}.submissions"))
+    }
+
+    pub fn load(dir: &Path, year: u16, day: u8) -> Result<Self, String> {
+        let path = Self::path(dir, year, day);
+        let entries = match std::fs::read_to_string(&path) {
+            Ok(log) => parse(&log).map_err(|e| format!("{}: {e}", path.display()))?,
+            Err(_) => vec![],
+        };
+        Ok(Self { path, entries })
+    }
+
+    /// Checks an answer against the log, returning why it should not be submitted
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), String> {
+        let mut entries = self.entries.iter().filter(|s| s.part == part);
+        if let Some(s) = entries.clone().find(|s| s.verdict == Verdict::Correct) {
+            return Err(format!("part {part} is already solved with {}", s.answer));
+        }
+        if let Some(s) = entries
+            .clone()
+            .find(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Err(format!(
+                "{answer} was already submitted and is {}",
+                s.verdict.name()
+            ));
+        }
+
+        let Ok(n) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        entries.try_for_each(|s| match (s.verdict, s.answer.parse::<i128>()) {
+            (Verdict::TooHigh, Ok(high)) if n >= high => {
+                Err(format!("{answer} is not below {high}, which is too high"))
+            }
+            (Verdict::TooLow, Ok(low)) if n <= low => {
+                Err(format!("{answer} is not above {low}, which is too low"))
+            }
+            _ => Ok(()),
+        })
+    }
+
+    /// Appends a submission to the log. Verdicts which say nothing about the answer are skipped.
+    pub fn record(&mut self, submission: Submission) -> Result<(), String> {
+        if !submission.verdict.is_final() {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| format!("{}: {e}", self.path.display()))?;
+        writeln!(
+            file,
+            "{} {} {}",
+            submission.part,
+            submission.verdict.name(),
+            submission.answer
+        )
+        .map_err(|e| format!("{}: {e}", self.path.display()))?;
+        self.entries.push(submission);
+        Ok(())
+    }
+}
+
+fn parse(log: &str) -> Result<Vec<Submission>, String> {
+    log.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, line)| {
+            let mut fields = line.splitn(3, ' ');
+            let part = fields
+                .next()
+                .and_then(|p| p.parse().ok())
+                .and_then(Part::from_num);
+            let verdict = fields.next().and_then(Verdict::from_name);
+            match (part, verdict, fields.next()) {
+                (Some(part), Some(verdict), Some(answer)) => Ok(Submission {
+                    part,
+                    answer: answer.to_string(),
+                    verdict,
+                }),
+                _ => Err(format!("line {}: malformed entry `{line}`", i + 1)),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let subs = Submissions {
+            path: PathBuf::new(),
+            entries: parse("1 too-high 100\n1 too-low 10\n1 wrong 50\n2 correct abc\n").unwrap(),
+        };
+        assert!(subs.check(Part::One, "42").is_ok());
+        assert!(subs.check(Part::One, "50").is_err());
+        assert!(subs.check(Part::One, "100").is_err());
+        assert!(subs.check(Part::One, "150").is_err());
+        assert!(subs.check(Part::One, "5").is_err());
+        assert!(subs.check(Part::Two, "xyz").is_err());
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(parse("1 correct 5\n3 wrong 1\n").is_err());
+        assert!(parse("1 maybe 5\n").is_err());
+    }
+}
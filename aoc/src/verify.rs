@@ -0,0 +1,205 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+
+use aocutils::reading::input::InputSource;
+use aocutils::runner::{Day, Part};
+
+use crate::answers::Answers;
+
+/// Result of checking one part against its recorded answer
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Outcome {
+    Ok,
+    Regression {
+        expected: String,
+        actual: String,
+    },
+    /// No answer is recorded for this part
+    Missing {
+        actual: String,
+    },
+    Panic(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ok => write!(f, "ok"),
+            Self::Regression { expected, actual } => {
+                write!(f, "REGRESSION expected {expected}, got {actual}")
+            }
+            Self::Missing { actual } => write!(f, "no recorded answer (got {actual})"),
+            Self::Panic(msg) => write!(f, "PANIC {msg}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: &'static Day,
+    /// Outcome of every solved part, or why the day could not be run
+    pub parts: Result<Vec<(Part, Outcome)>, String>,
+}
+
+/// Counts of each kind of outcome
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub ok: usize,
+    pub regressions: usize,
+    pub missing: usize,
+    pub panics: usize,
+    pub no_input: usize,
+}
+
+impl Summary {
+    pub fn from_reports(reports: &[DayReport]) -> Self {
+        let mut summary = Self::default();
+        for report in reports {
+            let Ok(parts) = &report.parts else {
+                summary.no_input += 1;
+                continue;
+            };
+            for (_, outcome) in parts {
+                match outcome {
+                    Outcome::Ok => summary.ok += 1,
+                    Outcome::Regression { .. } => summary.regressions += 1,
+                    Outcome::Missing { .. } => summary.missing += 1,
+                    Outcome::Panic(_) => summary.panics += 1,
+                }
+            }
+        }
+        summary
+    }
+
+    pub fn failed(&self) -> bool {
+        self.regressions > 0 || self.panics > 0
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ok, {} regressed, {} missing, {} panicked, {} days without input",
+            self.ok, self.regressions, self.missing, self.panics, self.no_input
+        )
+    }
+}
+
+/// Runs a part, turning a panic or invalid input into its message
+pub fn solve_catching(day: &Day, input: &str, part: Part) -> Result<Option<String>, String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| day.solve(input, part))) {
+        Ok(Ok(run)) => Ok(run.map(|run| run.answer)),
+        Ok(Err(e)) => Err(format!("invalid input, {e}")),
+        Err(payload) => Err(panic_message(payload)),
+    }
+}
+
+/// Message of a panic caught with [panic::catch_unwind]
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+pub fn check(answers: &Answers, day: &Day, part: Part, actual: Result<String, String>) -> Outcome {
+    match (actual, answers.get(day.year, day.day, part)) {
+        (Err(msg), _) => Outcome::Panic(msg),
+        (Ok(actual), Some(expected)) if actual == expected => Outcome::Ok,
+        (Ok(actual), Some(expected)) => Outcome::Regression { expected, actual },
+        (Ok(actual), None) => Outcome::Missing { actual },
+    }
+}
+
+/// Runs every solved part of `days` against its cached input
+pub fn verify(days: &[&'static Day], answers: &Answers) -> Vec<DayReport> {
+    // Panics are reported per part instead of being printed as they happen
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let reports = days
+        .iter()
+        .map(|&day| {
+            let parts = day
+                .load_input(&InputSource::Cache)
+                .map_err(|e| e.to_string())
+                .map(|input| {
+                    Part::iter_all()
+                        .filter_map(|part| {
+                            let actual = solve_catching(day, &input, part).transpose()?;
+                            Some((part, check(answers, day, part, actual)))
+                        })
+                        .collect()
+                });
+            DayReport { day, parts }
+        })
+        .collect();
+
+    panic::set_hook(hook);
+    reports
+}
+
+#[cfg(test)]
+mod tests {
+    use aocutils::reading::ParseResult;
+    use aocutils::runner::{Solution, Unsolved};
+
+    use super::*;
+
+    struct Panicky;
+
+    impl Solution for Panicky {
+        type Input<'a> = &'a str;
+        type Answer1 = usize;
+        type Answer2 = Unsolved;
+
+        fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+            Ok(input)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+            input.parse().expect("not a number")
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> Self::Answer2 {
+            Unsolved
+        }
+    }
+
+    const DAY: Day = aocutils::day!(2000, 1, Panicky);
+
+    #[test]
+    fn outcomes() {
+        let mut answers = Answers::default();
+        answers.set(2000, 1, Part::One, "5");
+
+        let check_input = |input| {
+            check(
+                &answers,
+                &DAY,
+                Part::One,
+                solve_catching(&DAY, input, Part::One).map(Option::unwrap),
+            )
+        };
+        assert_eq!(check_input("5"), Outcome::Ok);
+        assert_eq!(
+            check_input("6"),
+            Outcome::Regression {
+                expected: "5".to_string(),
+                actual: "6".to_string()
+            }
+        );
+        assert!(matches!(check_input("x"), Outcome::Panic(msg) if msg.contains("not a number")));
+        assert_eq!(solve_catching(&DAY, "5", Part::Two), Ok(None));
+
+        assert_eq!(
+            check(&Answers::default(), &DAY, Part::One, Ok("5".to_string())),
+            Outcome::Missing {
+                actual: "5".to_string()
+            }
+        );
+    }
+}
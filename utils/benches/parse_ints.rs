@@ -0,0 +1,77 @@
+//! Compares ways of parsing a large input of integers, run with `cargo bench -p aocutils`
+
+use aocutils::bench::{BenchConfig, Pretty, bench};
+use aocutils::rand::Rng;
+use aocutils::reading::{ParseResult, fast, numbers};
+
+/// Two columns of numbers, like 2024 day 1
+fn columns(rng: &mut Rng, lines: usize) -> String {
+    (0..lines)
+        .map(|_| {
+            format!(
+                "{}   {}\n",
+                rng.range(10000..100000),
+                rng.range(10000..100000)
+            )
+        })
+        .collect()
+}
+
+/// One large number per line, like 2024 day 22
+fn secrets(rng: &mut Rng, lines: usize) -> String {
+    (0..lines)
+        .map(|_| format!("{}\n", rng.range(0..1 << 24)))
+        .collect()
+}
+
+fn main() {
+    let config = BenchConfig {
+        warmup: 5,
+        iterations: 50,
+    };
+    let mut rng = Rng::new(1);
+    let inputs = [
+        ("columns", columns(&mut rng, 200_000)),
+        ("secrets", secrets(&mut rng, 200_000)),
+    ];
+
+    type Parser = fn(&str) -> u64;
+    let parsers: [(&str, Parser); 5] = [
+        ("str::parse", |s| {
+            s.split_ascii_whitespace()
+                .map(|n| n.parse::<u64>().unwrap())
+                .sum()
+        }),
+        ("numbers::uints", |s| {
+            numbers::uints::<u64>(s).sum::<ParseResult<u64>>().unwrap()
+        }),
+        ("fast::uints", |s| fast::uints::<u64>(s.as_bytes()).sum()),
+        ("fast::ints", |s| {
+            fast::ints::<i64>(s.as_bytes()).sum::<i64>() as u64
+        }),
+        ("scalar", |s| {
+            let mut bytes = s.as_bytes();
+            let mut sum = 0;
+            while let Some(start) = bytes.iter().position(u8::is_ascii_digit) {
+                let (value, len) = fast::leading_u64_scalar(&bytes[start..]);
+                sum += value;
+                bytes = &bytes[start + len..];
+            }
+            sum
+        }),
+    ];
+
+    for (name, input) in &inputs {
+        println!("{name} ({} bytes)", input.len());
+        let expected = (parsers[0].1)(input);
+        for (parser, f) in parsers {
+            assert_eq!(f(input), expected, "{parser} disagrees");
+            let stats = bench(config, || f(input));
+            println!(
+                "  {parser:<16} {:>10} ± {}",
+                Pretty(stats.median),
+                Pretty(stats.stddev)
+            );
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring
+    pub warmup: usize,
+    /// Timed runs
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// Summary statistics over a set of timed runs
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    /// Population standard deviation
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Returns [None] if there are no samples
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+        let n = samples.len();
+        let mid = n / 2;
+        let median = if n.is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Some(Self {
+            samples: n,
+            min: samples[0],
+            max: samples[n - 1],
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Times `f` according to `config`. The output of `f` is passed through [black_box].
+///
+/// At least one timed iteration is always run.
+pub fn bench<T, F>(config: BenchConfig, mut f: F) -> Stats
+where
+    F: FnMut() -> T,
+{
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let mut samples: Vec<_> = (0..config.iterations.max(1))
+        .map(|_| {
+            let now = Instant::now();
+            black_box(f());
+            now.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&mut samples).expect("at least one iteration")
+}
+
+/// Formats a [Duration] with a unit suited to its magnitude, e.g. `12.34 ms`
+pub struct Pretty(pub Duration);
+
+impl Display for Pretty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        if nanos < 1e3 {
+            return f.pad(&format!("{nanos} ns"));
+        }
+        let (value, unit) = if nanos < 1e6 {
+            (nanos / 1e3, "μs")
+        } else if nanos < 1e9 {
+            (nanos / 1e6, "ms")
+        } else {
+            (nanos / 1e9, "s")
+        };
+        let s = format!("{value:.2} {unit}");
+        f.pad(&s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut samples = [3, 1, 4, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(4));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn iterations() {
+        let mut runs = 0;
+        let stats = bench(
+            BenchConfig {
+                warmup: 2,
+                iterations: 5,
+            },
+            || runs += 1,
+        );
+        assert_eq!(runs, 7);
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn pretty() {
+        assert_eq!(Pretty(Duration::from_nanos(12)).to_string(), "12 ns");
+        assert_eq!(Pretty(Duration::from_micros(1500)).to_string(), "1.50 ms");
+        assert_eq!(
+            format!("{:>9}", Pretty(Duration::from_secs(2))),
+            "   2.00 s"
+        );
+    }
+}
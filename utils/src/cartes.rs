@@ -1,6 +1,8 @@
+pub mod automaton;
 pub mod dim2;
 pub mod dim3;
 
+pub mod flood;
 pub mod grid;
 pub mod pathing;
 pub mod pos;
@@ -0,0 +1,308 @@
+use std::collections::HashSet;
+
+use super::dim2::grid::{FULL, Grid2, Pos};
+use super::dim2::sparse::SparseGrid2;
+use super::grid::Grid;
+
+/// The full neighbours of a cell as passed to automaton rules, diagonals included.
+/// Cells on the edge of a [Grid2] have fewer than 8.
+#[derive(Debug, Clone)]
+pub struct Neighbours<'a, C> {
+    cells: [Option<&'a C>; 8],
+    next: usize,
+}
+
+impl<'a, C> Neighbours<'a, C> {
+    fn new(iter: impl Iterator<Item = &'a C>) -> Self {
+        let mut cells = [None; 8];
+        for (slot, cell) in cells.iter_mut().zip(iter) {
+            *slot = Some(cell);
+        }
+        Self { cells, next: 0 }
+    }
+}
+
+impl<'a, C> Iterator for Neighbours<'a, C> {
+    type Item = &'a C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cell = *self.cells.get(self.next)?;
+        self.next += 1;
+        cell
+    }
+}
+
+/// Grids which an [Automaton] can step
+pub trait Generation: Clone {
+    type Cell;
+
+    /// Writes the generation following `self` into `next`, computing each cell with `rule`.
+    /// Returns the number of cells which changed.
+    fn advance<F>(&self, next: &mut Self, rule: &F) -> usize
+    where
+        F: Fn(&Self::Cell, Neighbours<'_, Self::Cell>) -> Self::Cell;
+}
+
+impl<C: Eq + Clone> Generation for Grid2<C> {
+    type Cell = C;
+
+    fn advance<F>(&self, next: &mut Self, rule: &F) -> usize
+    where
+        F: Fn(&C, Neighbours<'_, C>) -> C,
+    {
+        let mut changed = 0;
+        for (idx, cell) in self.data.iter().enumerate() {
+            let pos = Pos::from_idx(idx, self.cols);
+            let new = rule(cell, Neighbours::new(self.get_neighbours_full(pos)));
+            changed += (new != *cell) as usize;
+            next.data[idx] = new;
+        }
+        changed
+    }
+}
+
+/// A [SparseGrid2] whose missing cells all hold `fill`, e.g. dead cells in an unbounded game of life.
+///
+/// Cells which become `fill` are removed, so only cells next to stored ones can change.
+#[derive(Debug, Clone)]
+pub struct Sparse<C> {
+    pub cells: SparseGrid2<C>,
+    pub fill: C,
+    /// Scratch space for the positions visited in a step
+    seen: HashSet<Pos>,
+}
+
+impl<C> Sparse<C> {
+    pub fn new(cells: SparseGrid2<C>, fill: C) -> Self {
+        Self {
+            cells,
+            fill,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<C: Eq> Sparse<C> {
+    pub fn get(&self, pos: Pos) -> &C {
+        self.cells.get_cell(pos).unwrap_or(&self.fill)
+    }
+}
+
+impl<C: Eq + Clone> Generation for Sparse<C> {
+    type Cell = C;
+
+    fn advance<F>(&self, next: &mut Self, rule: &F) -> usize
+    where
+        F: Fn(&C, Neighbours<'_, C>) -> C,
+    {
+        next.cells.clear();
+        next.seen.clear();
+        let mut changed = 0;
+        for stored in self.cells.positions() {
+            for pos in FULL.map(|d| stored + d).into_iter().chain([stored]) {
+                if !next.seen.insert(pos) {
+                    continue;
+                }
+                let cell = self.get(pos);
+                let neighbours = FULL.iter().map(|&d| self.get(pos + d));
+                let new = rule(cell, Neighbours::new(neighbours));
+                changed += (new != *cell) as usize;
+                if new != self.fill {
+                    next.cells.insert(pos, new);
+                }
+            }
+        }
+        next.fill = self.fill.clone();
+        changed
+    }
+}
+
+/// Steps a grid of cells through generations with a rule computing each cell from
+/// itself and its [Neighbours].
+///
+/// Keeps a second grid around to write the next generation into,
+/// so stepping does not allocate a new grid every time.
+#[derive(Debug, Clone)]
+pub struct Automaton<G> {
+    current: G,
+    next: G,
+    steps: usize,
+}
+
+impl<G: Generation> Automaton<G> {
+    pub fn new(grid: G) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            steps: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &G {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> G {
+        self.current
+    }
+
+    /// Number of steps taken so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Updates every cell at once, each seeing only the previous generation.
+    /// Returns the number of cells which changed.
+    pub fn step<F>(&mut self, rule: F) -> usize
+    where
+        F: Fn(&G::Cell, Neighbours<'_, G::Cell>) -> G::Cell,
+    {
+        let changed = self.current.advance(&mut self.next, &rule);
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.steps += 1;
+        changed
+    }
+
+    pub fn run<F>(&mut self, steps: usize, rule: F) -> &G
+    where
+        F: Fn(&G::Cell, Neighbours<'_, G::Cell>) -> G::Cell,
+    {
+        for _ in 0..steps {
+            self.step(&rule);
+        }
+        &self.current
+    }
+
+    /// Steps until nothing changes anymore. Returns the number of steps which changed something,
+    /// the final step which did not is not counted in [steps][Self::steps] either.
+    pub fn run_until_stable<F>(&mut self, rule: F) -> usize
+    where
+        F: Fn(&G::Cell, Neighbours<'_, G::Cell>) -> G::Cell,
+    {
+        let start = self.steps;
+        while self.step(&rule) > 0 {}
+        self.steps -= 1;
+        self.steps - start
+    }
+}
+
+impl<C: Eq + Clone> Automaton<Sparse<C>> {
+    /// Automaton over the cells of `cells`, every other position holding `fill`
+    pub fn sparse(cells: SparseGrid2<C>, fill: C) -> Self {
+        Self::new(Sparse::new(cells, fill))
+    }
+}
+
+impl<C: Eq + Clone> Automaton<Grid2<C>> {
+    /// Updates cells one by one, row by row, each seeing the already updated cells before it.
+    /// Returns the number of cells which changed.
+    pub fn step_in_place<F>(&mut self, rule: F) -> usize
+    where
+        F: Fn(&C, Neighbours<'_, C>) -> C,
+    {
+        let grid = &mut self.current;
+        let mut changed = 0;
+        for idx in 0..grid.data.len() {
+            let pos = Pos::from_idx(idx, grid.cols);
+            let new = rule(
+                &grid.data[idx],
+                Neighbours::new(grid.get_neighbours_full(pos)),
+            );
+            if new != grid.data[idx] {
+                changed += 1;
+                grid.data[idx] = new;
+            }
+        }
+        self.steps += 1;
+        changed
+    }
+
+    /// [run_until_stable][Self::run_until_stable] with [in-place][Self::step_in_place] steps
+    pub fn run_in_place_until_stable<F>(&mut self, rule: F) -> usize
+    where
+        F: Fn(&C, Neighbours<'_, C>) -> C,
+    {
+        let start = self.steps;
+        while self.step_in_place(&rule) > 0 {}
+        self.steps -= 1;
+        self.steps - start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartes::dim2::vec::Vec2;
+
+    fn life(alive: &bool, neighbours: Neighbours<'_, bool>) -> bool {
+        let n = neighbours.filter(|&&c| c).count();
+        n == 3 || (*alive && n == 2)
+    }
+
+    fn grid(s: &str) -> Grid2<bool> {
+        Grid2::from_str_2(s, |c| Some(c == b'#'))
+    }
+
+    #[test]
+    fn dense() {
+        let blinker = grid(".....\n..#..\n..#..\n..#..\n.....");
+        let mut life_game = Automaton::new(blinker.clone());
+        assert_eq!(life_game.step(life), 4);
+        assert_eq!(life_game.grid(), &grid(".....\n.....\n.###.\n.....\n....."));
+        assert_eq!(life_game.run(3, life), &blinker);
+        assert_eq!(life_game.steps(), 4);
+
+        let mut block = Automaton::new(grid("##.\n##.\n..."));
+        assert_eq!(block.run_until_stable(life), 0);
+        assert_eq!(block.steps(), 0);
+    }
+
+    #[test]
+    fn in_place() {
+        // A signal travelling right, which in-place updates carry across in a single step
+        let wire = Grid2::from_raw(vec![1u8, 0, 0, 0], 4, 1);
+        let rule = |cell: &u8, mut n: Neighbours<'_, u8>| (*cell).max(u8::from(n.any(|&c| c == 1)));
+        let mut sync = Automaton::new(wire.clone());
+        assert_eq!(sync.run_until_stable(rule), 3);
+        let mut eager = Automaton::new(wire);
+        assert_eq!(eager.run_in_place_until_stable(rule), 1);
+        assert_eq!(sync.grid(), eager.grid());
+    }
+
+    #[test]
+    fn removal() {
+        // 2025 day 4: rolls with fewer than 4 neighbouring rolls are removed until none are left
+        let rolls = grid(
+            "..##.####.
+###.#.#.##
+#####.#.##
+#.####..#.
+##.####.##
+.#######.#
+.#.#.#.###
+#.###.####
+.########.
+#.#.###.#.",
+        );
+        let before = rolls.iter().filter(|&(_, &c)| c).count();
+        let mut automaton = Automaton::new(rolls);
+        let rounds = automaton.run_until_stable(|&roll, n| roll && n.filter(|&&c| c).count() >= 4);
+        let after = automaton.grid().iter().filter(|&(_, &c)| c).count();
+        assert_eq!(before - after, 43);
+        assert_eq!(rounds, automaton.steps());
+    }
+
+    #[test]
+    fn sparse() {
+        let glider: SparseGrid2<bool> = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+            .into_iter()
+            .map(|p| (Vec2::from(p), true))
+            .collect();
+        let mut automaton = Automaton::sparse(glider.clone(), false);
+        automaton.run(8, life);
+        let moved: SparseGrid2<bool> = glider.iter().map(|(p, &c)| (p + Vec2(2, 2), c)).collect();
+        assert_eq!(automaton.grid().cells, moved);
+        assert_eq!(automaton.grid().cells.len(), 5);
+        assert!(!automaton.grid().get(Vec2(-100, 7)));
+    }
+}
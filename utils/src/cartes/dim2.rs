@@ -1,5 +1,13 @@
+pub mod bitgrid;
+
 pub mod dir;
 
 pub mod grid;
 
+pub mod region;
+
+pub mod sparse;
+
+pub mod torus;
+
 pub mod vec;
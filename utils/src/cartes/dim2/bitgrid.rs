@@ -0,0 +1,429 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::cartes::dim2::grid::{ADJ, FULL, Grid2, Pos};
+use crate::cartes::dim2::vec::Vec2;
+use crate::cartes::flood::Connectivity;
+use crate::cartes::grid::{Grid, GridMut};
+
+/// 2-dimensional grid of booleans with bounds `(0,0)..(cols,rows)`, packed into bits.
+///
+/// Every row is stored as `u64` words, cell `(x,y)` being bit `x % 64` of word `x / 64` of row `y`.
+/// Bulk operations work on whole words at a time.
+#[derive(Debug, PartialEq, Eq, Clone, Default, Hash)]
+pub struct BitGrid2 {
+    words: Vec<u64>,
+    /// Words per row
+    stride: usize,
+    pub cols: usize,
+    pub rows: usize,
+}
+
+impl BitGrid2 {
+    /// Grid with every cell unset
+    pub fn new(cols: usize, rows: usize) -> Self {
+        let stride = cols.div_ceil(64);
+        Self {
+            words: vec![0; stride * rows],
+            stride,
+            cols,
+            rows,
+        }
+    }
+
+    /// Cells set where `f` holds for the cell of `grid`
+    pub fn from_grid<C, F>(grid: &Grid2<C>, f: F) -> Self
+    where
+        F: Fn(&C) -> bool,
+    {
+        let mut bits = Self::new(grid.cols, grid.rows);
+        for (idx, cell) in grid.data.iter().enumerate() {
+            if f(cell) {
+                bits.set(Pos::from_idx(idx, grid.cols), true);
+            }
+        }
+        bits
+    }
+
+    pub fn to_grid(&self) -> Grid2<bool> {
+        let data = (0..self.cols * self.rows)
+            .map(|idx| self.get(Pos::from_idx(idx, self.cols)))
+            .collect();
+        Grid2::from_raw(data, self.cols, self.rows)
+    }
+
+    fn in_bounds(&self, pos: Pos) -> bool {
+        (0..self.cols as isize).contains(&pos.0) && (0..self.rows as isize).contains(&pos.1)
+    }
+
+    /// Word index and bit mask of an in-bounds `pos`
+    fn locate(&self, pos: Pos) -> (usize, u64) {
+        let (x, y) = (pos.0 as usize, pos.1 as usize);
+        (y * self.stride + x / 64, 1 << (x % 64))
+    }
+
+    /// Whether the cell at `pos` is set, [false] when out-of-bounds
+    pub fn get(&self, pos: Pos) -> bool {
+        if !self.in_bounds(pos) {
+            return false;
+        }
+        let (word, bit) = self.locate(pos);
+        self.words[word] & bit != 0
+    }
+
+    /// # Panics
+    /// When `pos` is out-of-bounds
+    pub fn set(&mut self, pos: Pos, value: bool) {
+        assert!(self.in_bounds(pos), "position {pos:?} is out of bounds");
+        let (word, bit) = self.locate(pos);
+        if value {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+    }
+
+    /// The words of row `y`. Bits past `cols` are always unset.
+    pub fn row_words(&self, y: usize) -> &[u64] {
+        &self.words[y * self.stride..(y + 1) * self.stride]
+    }
+
+    /// Number of set cells
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Positions of the set cells, row by row
+    pub fn ones(&self) -> impl Iterator<Item = Pos> {
+        self.words.iter().enumerate().flat_map(move |(idx, &word)| {
+            let (y, x0) = (idx / self.stride, idx % self.stride * 64);
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(Pos::from(((x0 + bit) as isize, y as isize)))
+            })
+        })
+    }
+
+    /// Unsets the bits past `cols` in the last word of every row
+    fn clear_tail(&mut self) {
+        let tail = self.cols % 64;
+        if tail == 0 {
+            return;
+        }
+        let mask = (1 << tail) - 1;
+        for row in self.words.chunks_exact_mut(self.stride) {
+            *row.last_mut().unwrap() &= mask;
+        }
+    }
+
+    /// Every cell moved by `(dx,dy)`. Cells moved out of bounds are lost, cells moved in are unset.
+    pub fn shift(&self, dx: isize, dy: isize) -> Self {
+        let mut shifted = Self::new(self.cols, self.rows);
+        if self.stride == 0 {
+            return shifted;
+        }
+        let (q, r) = (dx.unsigned_abs() / 64, dx.unsigned_abs() % 64);
+        for y in 0..self.rows {
+            let src = y as isize - dy;
+            if !(0..self.rows as isize).contains(&src) {
+                continue;
+            }
+            let src = self.row_words(src as usize);
+            let word = |w: isize| -> u64 {
+                if (0..self.stride as isize).contains(&w) {
+                    src[w as usize]
+                } else {
+                    0
+                }
+            };
+            let dst = &mut shifted.words[y * self.stride..(y + 1) * self.stride];
+            for (w, out) in dst.iter_mut().enumerate() {
+                let w = w as isize;
+                *out = if dx >= 0 {
+                    let near = word(w - q as isize) << r;
+                    let far = if r == 0 {
+                        0
+                    } else {
+                        word(w - q as isize - 1) >> (64 - r)
+                    };
+                    near | far
+                } else {
+                    let near = word(w + q as isize) >> r;
+                    let far = if r == 0 {
+                        0
+                    } else {
+                        word(w + q as isize + 1) << (64 - r)
+                    };
+                    near | far
+                };
+            }
+        }
+        shifted.clear_tail();
+        shifted
+    }
+
+    /// Number of set neighbours of every cell, as bit-sliced counters
+    pub fn neighbour_counts(&self, connectivity: Connectivity) -> NeighbourCounts {
+        let offsets: &[Pos] = match connectivity {
+            Connectivity::Adjacent => &ADJ,
+            Connectivity::Full => &FULL,
+        };
+        let mut planes: [Self; 4] = std::array::from_fn(|_| Self::new(self.cols, self.rows));
+        for &Vec2(dx, dy) in offsets {
+            // The neighbour at (dx,dy) is seen by shifting it the other way
+            let shifted = self.shift(-dx, -dy);
+            for (i, &input) in shifted.words.iter().enumerate() {
+                // Ripple-carry add of a single bit per cell
+                let mut carry = input;
+                for plane in planes.iter_mut() {
+                    let sum = plane.words[i] ^ carry;
+                    carry &= plane.words[i];
+                    plane.words[i] = sum;
+                }
+            }
+        }
+        NeighbourCounts { planes }
+    }
+
+    fn zip_assign(&mut self, rhs: &Self, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.cols, self.rows),
+            (rhs.cols, rhs.rows),
+            "grids differ in size"
+        );
+        for (a, &b) in self.words.iter_mut().zip(&rhs.words) {
+            *a = f(*a, b);
+        }
+    }
+}
+
+macro_rules! impl_bitop {
+    ($($op:ident $method:ident $op_assign:ident $method_assign:ident $f:expr;)+) => {
+        $(
+        impl $op_assign<&BitGrid2> for BitGrid2 {
+            fn $method_assign(&mut self, rhs: &BitGrid2) {
+                self.zip_assign(rhs, $f);
+            }
+        }
+
+        impl $op for &BitGrid2 {
+            type Output = BitGrid2;
+
+            fn $method(self, rhs: &BitGrid2) -> BitGrid2 {
+                let mut out = self.clone();
+                out.zip_assign(rhs, $f);
+                out
+            }
+        }
+        )+
+    };
+}
+
+impl_bitop! {
+    BitAnd bitand BitAndAssign bitand_assign |a, b| a & b;
+    BitOr bitor BitOrAssign bitor_assign |a, b| a | b;
+    BitXor bitxor BitXorAssign bitxor_assign |a, b| a ^ b;
+}
+
+impl Not for &BitGrid2 {
+    type Output = BitGrid2;
+
+    fn not(self) -> BitGrid2 {
+        let mut out = BitGrid2 {
+            words: self.words.iter().map(|w| !w).collect(),
+            ..*self
+        };
+        out.clear_tail();
+        out
+    }
+}
+
+/// Per-cell neighbour counts from [BitGrid2::neighbour_counts],
+/// stored as 4 grids each holding one bit of the count
+#[derive(Debug, Clone)]
+pub struct NeighbourCounts {
+    planes: [BitGrid2; 4],
+}
+
+impl NeighbourCounts {
+    /// Count at `pos`, `0` when out-of-bounds
+    pub fn get(&self, pos: Pos) -> u8 {
+        self.planes
+            .iter()
+            .enumerate()
+            .map(|(i, plane)| (plane.get(pos) as u8) << i)
+            .sum()
+    }
+
+    /// Cells with exactly `n` neighbours set
+    pub fn exactly(&self, n: u8) -> BitGrid2 {
+        let mut out = !&BitGrid2::new(self.planes[0].cols, self.planes[0].rows);
+        for (i, plane) in self.planes.iter().enumerate() {
+            if n >> i & 1 == 1 {
+                out &= plane;
+            } else {
+                out &= &!plane;
+            }
+        }
+        out
+    }
+
+    /// Cells with at least `n` neighbours set
+    pub fn at_least(&self, n: u8) -> BitGrid2 {
+        let mut out = BitGrid2::new(self.planes[0].cols, self.planes[0].rows);
+        for k in n..=8 {
+            out |= &self.exactly(k);
+        }
+        out
+    }
+}
+
+/// Cells are bits, so they can be read but not borrowed mutably and there is no [GridMut] impl.
+/// Use [BitGrid2::set] instead.
+impl Grid for BitGrid2 {
+    type Pos = Pos;
+    type Cell = bool;
+
+    fn contains_pos(&self, pos: Pos) -> bool {
+        self.in_bounds(pos)
+    }
+
+    fn get_cell_unchecked(&self, pos: Self::Pos) -> &Self::Cell {
+        if self.get(pos) { &true } else { &false }
+    }
+
+    fn get_neighbours_adj_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        ADJ.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.in_bounds(*pos))
+    }
+
+    fn get_neighbours_full_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        FULL.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.in_bounds(*pos))
+    }
+
+    fn map<F, T>(self, f: F) -> impl GridMut<Pos = Self::Pos, Cell = T>
+    where
+        F: Fn(Self::Cell) -> T,
+        T: Eq,
+    {
+        let data = (0..self.cols * self.rows)
+            .map(|idx| f(self.get(Pos::from_idx(idx, self.cols))))
+            .collect();
+        Grid2::from_raw(data, self.cols, self.rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartes::dim2::vec::Vec2;
+    use crate::rand::Rng;
+
+    fn bits(s: &str) -> BitGrid2 {
+        BitGrid2::from_grid(&Grid2::from_str_2(s, Some), |&c| c == b'#')
+    }
+
+    fn random(rng: &mut Rng, cols: usize, rows: usize) -> BitGrid2 {
+        let mut grid = BitGrid2::new(cols, rows);
+        for idx in 0..cols * rows {
+            grid.set(Pos::from_idx(idx, cols), rng.bool());
+        }
+        grid
+    }
+
+    #[test]
+    fn cells() {
+        let mut g = bits("#..\n.#.");
+        assert!(g.get(Vec2(0, 0)) && g.get(Vec2(1, 1)));
+        assert!(!g.get(Vec2(2, 0)) && !g.get(Vec2(-1, 0)));
+        g.set(Vec2(2, 1), true);
+        g.set(Vec2(0, 0), false);
+        assert_eq!(g.count_ones(), 2);
+        assert_eq!(g.ones().collect::<Vec<_>>(), [Vec2(1, 1), Vec2(2, 1)]);
+        assert_eq!(g.get_cell(Vec2(1, 1)), Some(&true));
+        assert_eq!(g.get_neighbours_full(Vec2(1, 0)).filter(|&&c| c).count(), 2);
+        assert_eq!(g.clone().map(|c| c as u8).get_cell(Vec2(2, 1)), Some(&1));
+        assert_eq!(
+            g.to_grid().as_slice(),
+            [false, false, false, false, true, true]
+        );
+        assert!(BitGrid2::new(0, 3).is_empty());
+    }
+
+    #[test]
+    fn ops() {
+        let a = bits("##..\n#.#.");
+        let b = bits(".#.#\n#..#");
+        assert_eq!(&a & &b, bits(".#..\n#..."));
+        assert_eq!(&a | &b, bits("##.#\n#.##"));
+        assert_eq!(&a ^ &b, bits("#..#\n..##"));
+        assert_eq!(!&a, bits("..##\n.#.#"));
+        assert_eq!(a.shift(1, 0), bits(".##.\n.#.#"));
+        assert_eq!(a.shift(-1, 1), bits("....\n#..."));
+        assert_eq!(a.shift(0, -1), bits("#.#.\n...."));
+        assert!(a.shift(4, 0).is_empty());
+    }
+
+    #[test]
+    fn wide() {
+        let mut rng = Rng::new(23);
+        let g = random(&mut rng, 150, 3);
+        assert_eq!((!&g).count_ones(), 450 - g.count_ones());
+        for (dx, dy) in [(1, 0), (-1, 1), (63, 0), (-64, -1), (65, 2), (-130, 0)] {
+            let shifted = g.shift(dx, dy);
+            for y in 0..3 {
+                for x in 0..150 {
+                    let pos = Vec2(x, y);
+                    assert_eq!(
+                        shifted.get(pos),
+                        g.get(pos - Vec2(dx, dy)),
+                        "{dx} {dy} {pos:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn neighbour_counts() {
+        let mut rng = Rng::new(4);
+        let g = random(&mut rng, 70, 9);
+        let full = g.neighbour_counts(Connectivity::Full);
+        let adj = g.neighbour_counts(Connectivity::Adjacent);
+        for pos in g.to_grid().iter().map(|(pos, _)| pos) {
+            let count = |n: Vec<Pos>| n.into_iter().filter(|&p| g.get(p)).count() as u8;
+            assert_eq!(
+                full.get(pos),
+                count(g.get_neighbours_full_pos(pos).collect())
+            );
+            assert_eq!(adj.get(pos), count(g.get_neighbours_adj_pos(pos).collect()));
+            assert_eq!(full.exactly(3).get(pos), full.get(pos) == 3);
+            assert_eq!(full.at_least(4).get(pos), full.get(pos) >= 4);
+        }
+    }
+
+    #[test]
+    fn life() {
+        let step = |g: &BitGrid2| {
+            let counts = g.neighbour_counts(Connectivity::Full);
+            &counts.exactly(3) | &(g & &counts.exactly(2))
+        };
+        let glider = bits(".#....\n..#...\n###...\n......\n......\n......");
+        let mut g = glider.clone();
+        for _ in 0..4 {
+            g = step(&g);
+        }
+        assert_eq!(g, glider.shift(1, 1));
+    }
+}
@@ -1,9 +1,36 @@
 use std::ops::{Index, IndexMut};
 
-use crate::cartes::{dim2::vec::Vec2, grid::Grid};
+use crate::cartes::{
+    dim2::vec::Vec2,
+    grid::{Grid, GridMut},
+};
+
+pub mod lines;
+pub mod transform;
+pub mod view;
+
+pub use lines::{Line, LineMut};
+pub use transform::Orientation;
+pub use view::{Grid2View, Grid2ViewMut};
+
 /// Position types should be [Negateable][::std::ops::Neg]
 pub type Pos = Vec2<isize>;
 
+/// Offsets of the 4 orthogonal neighbours of a position
+pub const ADJ: [Pos; 4] = [Vec2(1, 0), Vec2(-1, 0), Vec2(0, 1), Vec2(0, -1)];
+
+/// Offsets of all 8 neighbours of a position, the [ADJ] ones first
+pub const FULL: [Pos; 8] = [
+    Vec2(1, 0),
+    Vec2(-1, 0),
+    Vec2(0, 1),
+    Vec2(0, -1),
+    Vec2(1, 1),
+    Vec2(-1, 1),
+    Vec2(1, -1),
+    Vec2(-1, -1),
+];
+
 impl Pos {
     pub fn from_idx(idx: usize, cols: usize) -> Self {
         Vec2((idx % cols) as isize, (idx / cols) as isize)
@@ -26,6 +53,14 @@ impl crate::cartes::pos::Pos for Pos {
         let y = other.1 - self.1;
         x * x + y * y
     }
+
+    fn min_axes(self, other: Self) -> Self {
+        Vec2(self.0.min(other.0), self.1.min(other.1))
+    }
+
+    fn max_axes(self, other: Self) -> Self {
+        Vec2(self.0.max(other.0), self.1.max(other.1))
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
@@ -56,63 +91,19 @@ where
         &self.data[pos.to_idx(self.cols)]
     }
 
-    fn get_cell_mut_unchecked(&mut self, pos: Self::Pos) -> &mut Self::Cell {
-        &mut self.data[pos.to_idx(self.cols)]
-    }
-
-    fn get_neighbours_adj(&self, pos: Self::Pos) -> impl Iterator<Item = &Self::Cell> {
-        [
-            self.get_cell(pos + From::from((1, 0))),
-            self.get_cell(pos + From::from((-1, 0))),
-            self.get_cell(pos + From::from((0, 1))),
-            self.get_cell(pos + From::from((0, -1))),
-        ]
-        .into_iter()
-        .flatten()
-    }
-
     fn get_neighbours_adj_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
-        [
-            pos + From::from((1, 0)),
-            pos + From::from((-1, 0)),
-            pos + From::from((0, 1)),
-            pos + From::from((0, -1)),
-        ]
-        .into_iter()
-        .filter(|pos| self.contains_pos(*pos))
-    }
-
-    fn get_neighbours_full(&self, pos: Self::Pos) -> impl Iterator<Item = &Self::Cell> {
-        [
-            self.get_cell(pos + From::from((1, 0))),
-            self.get_cell(pos + From::from((-1, 0))),
-            self.get_cell(pos + From::from((0, 1))),
-            self.get_cell(pos + From::from((0, -1))),
-            self.get_cell(pos + From::from((1, 1))),
-            self.get_cell(pos + From::from((-1, 1))),
-            self.get_cell(pos + From::from((1, -1))),
-            self.get_cell(pos + From::from((-1, -1))),
-        ]
-        .into_iter()
-        .flatten()
+        ADJ.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.contains_pos(*pos))
     }
 
     fn get_neighbours_full_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
-        [
-            pos + From::from((1, 0)),
-            pos + From::from((-1, 0)),
-            pos + From::from((0, 1)),
-            pos + From::from((0, -1)),
-            pos + From::from((1, 1)),
-            pos + From::from((-1, 1)),
-            pos + From::from((1, -1)),
-            pos + From::from((-1, -1)),
-        ]
-        .into_iter()
-        .filter(|pos| self.contains_pos(*pos))
-    }
-
-    fn map<F, T>(self, f: F) -> impl Grid<Pos = Self::Pos, Cell = T>
+        FULL.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.contains_pos(*pos))
+    }
+
+    fn map<F, T>(self, f: F) -> impl GridMut<Pos = Self::Pos, Cell = T>
     where
         F: Fn(Self::Cell) -> T,
         T: Eq,
@@ -128,6 +119,16 @@ where
         }
     }
 }
+
+impl<C> GridMut for Grid2<C>
+where
+    C: Eq,
+{
+    fn get_cell_mut_unchecked(&mut self, pos: Self::Pos) -> &mut Self::Cell {
+        &mut self.data[pos.to_idx(self.cols)]
+    }
+}
+
 impl<C> Index<Pos> for Grid2<C>
 where
     C: Eq,
@@ -154,6 +155,8 @@ where
     Self: Grid,
 {
     /// Parses string input including newlines as a grid cell
+    ///
+    /// See [reading::grid][crate::reading::grid] for parsing without newline cells
     pub fn from_str_1<F>(s: &str, p: F) -> Self
     where
         F: Copy + Fn(u8) -> C,
@@ -184,6 +187,9 @@ where
     ///
     /// If `p` returns `None`, the char is filtered out
     /// otherwise for `let Some(x) = p`, x is used
+    ///
+    /// Filtered out cells shift the rest of the grid. See [reading::grid][crate::reading::grid]
+    /// for parsing which rejects invalid cells and uneven rows instead
     pub fn from_str_2<F>(s: &str, p: F) -> Self
     where
         F: Copy + Fn(u8) -> Option<C>,
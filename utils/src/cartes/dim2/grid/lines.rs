@@ -0,0 +1,333 @@
+use std::marker::PhantomData;
+
+use crate::cartes::dim2::dir::Direction;
+
+use super::{Grid2, Pos};
+
+/// Number of cells from `start` onwards in steps of `step` which lie in a `cols` by `rows` grid
+fn line_len(start: Pos, step: Pos, cols: usize, rows: usize) -> usize {
+    assert!(step != Pos::zero(), "a line needs a non-zero step");
+    let axis = |p: isize, d: isize, len: usize| -> usize {
+        if !(0..len as isize).contains(&p) {
+            0
+        } else if d > 0 {
+            ((len as isize - 1 - p) / d + 1) as usize
+        } else if d < 0 {
+            (p / -d + 1) as usize
+        } else {
+            usize::MAX
+        }
+    };
+    axis(start.0, step.0, cols).min(axis(start.1, step.1, rows))
+}
+
+/// Cells along a straight line through a [Grid2], with their positions
+#[derive(Debug)]
+pub struct Line<'g, T> {
+    grid: &'g Grid2<T>,
+    pos: Pos,
+    step: Pos,
+    remaining: usize,
+}
+
+impl<T> Clone for Line<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'g, T> Line<'g, T> {
+    fn new(grid: &'g Grid2<T>, start: Pos, step: Pos) -> Self {
+        Self {
+            grid,
+            pos: start,
+            step,
+            remaining: line_len(start, step, grid.cols, grid.rows),
+        }
+    }
+}
+
+impl<'g, T> Iterator for Line<'g, T> {
+    type Item = (Pos, &'g T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let pos = self.pos;
+        self.pos += self.step;
+        self.remaining -= 1;
+        Some((pos, &self.grid.data[pos.to_idx(self.grid.cols)]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Line<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let pos = self.pos + self.step * self.remaining as isize;
+        Some((pos, &self.grid.data[pos.to_idx(self.grid.cols)]))
+    }
+}
+
+impl<T> ExactSizeIterator for Line<'_, T> {}
+impl<T> ::core::iter::FusedIterator for Line<'_, T> {}
+
+/// Mutable cells along a straight line through a [Grid2], with their positions
+pub struct LineMut<'g, T> {
+    data: *mut T,
+    cols: usize,
+    pos: Pos,
+    step: Pos,
+    remaining: usize,
+    _marker: PhantomData<&'g mut T>,
+}
+
+impl<'g, T> LineMut<'g, T> {
+    /// # Safety
+    /// Lines alive at the same time must not share cells
+    unsafe fn new(grid: &mut Grid2<T>, start: Pos, step: Pos) -> Self {
+        Self {
+            data: grid.data.as_mut_ptr(),
+            cols: grid.cols,
+            pos: start,
+            step,
+            remaining: line_len(start, step, grid.cols, grid.rows),
+            _marker: PhantomData,
+        }
+    }
+
+    fn cell(&mut self, pos: Pos) -> &'g mut T {
+        // Lines never visit a cell twice and their lengths keep them in bounds
+        unsafe { &mut *self.data.add(pos.to_idx(self.cols)) }
+    }
+}
+
+impl<'g, T> Iterator for LineMut<'g, T> {
+    type Item = (Pos, &'g mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let pos = self.pos;
+        self.pos += self.step;
+        self.remaining -= 1;
+        Some((pos, self.cell(pos)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for LineMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let pos = self.pos + self.step * self.remaining as isize;
+        Some((pos, self.cell(pos)))
+    }
+}
+
+impl<T> ExactSizeIterator for LineMut<'_, T> {}
+impl<T> ::core::iter::FusedIterator for LineMut<'_, T> {}
+
+/// Starts of the lines covering a `cols` by `rows` grid in steps of `step`,
+/// one of `(1,0)`, `(0,1)`, `(1,1)` and `(1,-1)`
+fn line_starts(step: Pos, cols: usize, rows: usize) -> Vec<Pos> {
+    if cols == 0 || rows == 0 {
+        return vec![];
+    }
+    let (cols, rows) = (cols as isize, rows as isize);
+    let left = |y| Pos::from((0, y));
+    match (step.0, step.1) {
+        (1, 0) => (0..rows).map(left).collect(),
+        (0, 1) => (0..cols).map(|x| Pos::from((x, 0))).collect(),
+        // Bottom left corner upwards, then along the top
+        (1, 1) => (0..rows)
+            .rev()
+            .map(left)
+            .chain((1..cols).map(|x| Pos::from((x, 0))))
+            .collect(),
+        // Top left corner downwards, then along the bottom
+        (1, -1) => (0..rows)
+            .map(left)
+            .chain((1..cols).map(|x| Pos::from((x, rows - 1))))
+            .collect(),
+        _ => unreachable!("unsupported line family"),
+    }
+}
+
+impl<T> Grid2<T> {
+    /// Cells of row `y` from left to right
+    pub fn row(&self, y: usize) -> Line<'_, T> {
+        Line::new(self, Pos::from((0, y as isize)), Pos::from((1, 0)))
+    }
+
+    /// Cells of column `x` from top to bottom
+    pub fn column(&self, x: usize) -> Line<'_, T> {
+        Line::new(self, Pos::from((x as isize, 0)), Pos::from((0, 1)))
+    }
+
+    /// Every row, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.rows).map(|y| self.row(y))
+    }
+
+    /// Every column, left to right
+    pub fn columns(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.cols).map(|x| self.column(x))
+    }
+
+    /// Every diagonal going down and right (`\`), starting from the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let step = Pos::from((1, 1));
+        line_starts(step, self.cols, self.rows)
+            .into_iter()
+            .map(move |start| Line::new(self, start, step))
+    }
+
+    /// Every anti-diagonal going up and right (`/`), starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let step = Pos::from((1, -1));
+        line_starts(step, self.cols, self.rows)
+            .into_iter()
+            .map(move |start| Line::new(self, start, step))
+    }
+
+    /// Cells from `pos` in `dir` until the edge of the grid, not including `pos` itself
+    pub fn ray(&self, pos: Pos, dir: Direction) -> Line<'_, T> {
+        self.ray_step(pos, dir.step())
+    }
+
+    /// Cells from `pos` in steps of `step` until the edge of the grid, not including `pos`.
+    /// Allows diagonal rays, e.g. with a step of `(1,1)`.
+    pub fn ray_step(&self, pos: Pos, step: Pos) -> Line<'_, T> {
+        Line::new(self, pos + step, step)
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> LineMut<'_, T> {
+        unsafe { LineMut::new(self, Pos::from((0, y as isize)), Pos::from((1, 0))) }
+    }
+
+    pub fn column_mut(&mut self, x: usize) -> LineMut<'_, T> {
+        unsafe { LineMut::new(self, Pos::from((x as isize, 0)), Pos::from((0, 1))) }
+    }
+
+    fn lines_mut(&mut self, step: Pos) -> impl Iterator<Item = LineMut<'_, T>> {
+        let starts = line_starts(step, self.cols, self.rows);
+        // Lines of one family never cross
+        starts
+            .into_iter()
+            .map(move |start| unsafe { LineMut::new(self, start, step) })
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = LineMut<'_, T>> {
+        self.lines_mut(Pos::from((1, 0)))
+    }
+
+    pub fn columns_mut(&mut self) -> impl Iterator<Item = LineMut<'_, T>> {
+        self.lines_mut(Pos::from((0, 1)))
+    }
+
+    pub fn diagonals_mut(&mut self) -> impl Iterator<Item = LineMut<'_, T>> {
+        self.lines_mut(Pos::from((1, 1)))
+    }
+
+    pub fn anti_diagonals_mut(&mut self) -> impl Iterator<Item = LineMut<'_, T>> {
+        self.lines_mut(Pos::from((1, -1)))
+    }
+
+    pub fn ray_mut(&mut self, pos: Pos, dir: Direction) -> LineMut<'_, T> {
+        self.ray_step_mut(pos, dir.step())
+    }
+
+    pub fn ray_step_mut(&mut self, pos: Pos, step: Pos) -> LineMut<'_, T> {
+        unsafe { LineMut::new(self, pos + step, step) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartes::dim2::vec::Vec2;
+
+    fn text<'a>(line: impl Iterator<Item = (Pos, &'a u8)>) -> String {
+        line.map(|(_, &c)| c as char).collect()
+    }
+
+    #[test]
+    fn lines() {
+        let g = Grid2::from_str_2("abc\ndef", Some);
+        assert_eq!(text(g.row(1)), "def");
+        assert_eq!(text(g.column(2)), "cf");
+        assert_eq!(text(g.row(1).rev()), "fed");
+        assert_eq!(g.row(2).count(), 0);
+        assert_eq!(g.rows().map(text).collect::<Vec<_>>(), ["abc", "def"]);
+        assert_eq!(
+            g.columns().map(text).collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            g.diagonals().map(text).collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            g.anti_diagonals().map(text).collect::<Vec<_>>(),
+            ["a", "db", "ec", "f"]
+        );
+        assert_eq!(g.column(1).next_back(), Some((Vec2(1, 1), &b'e')));
+        assert_eq!(Grid2::<u8>::default().diagonals().count(), 0);
+    }
+
+    #[test]
+    fn rays() {
+        let g = Grid2::from_str_2("abcd\nefgh\nijkl", Some);
+        assert_eq!(text(g.ray(Vec2(1, 1), Direction::Right)), "gh");
+        assert_eq!(text(g.ray(Vec2(1, 1), Direction::Up)), "b");
+        assert_eq!(text(g.ray(Vec2(0, 0), Direction::Left)), "");
+        assert_eq!(text(g.ray_step(Vec2(0, 0), Vec2(1, 1))), "fk");
+        assert_eq!(text(g.ray_step(Vec2(3, 0), Vec2(-2, 1))), "f");
+        assert_eq!(g.ray(Vec2(5, 5), Direction::Left).len(), 0);
+    }
+
+    #[test]
+    fn lines_mut() {
+        let mut g = Grid2::from_str_2("...\n...", Some);
+        for (i, column) in g.columns_mut().enumerate() {
+            column.for_each(|(_, c)| *c = b'0' + i as u8);
+        }
+        assert_eq!(g, Grid2::from_str_2("012\n012", Some));
+        for mut line in g.anti_diagonals_mut() {
+            if let Some((_, c)) = line.next_back() {
+                *c = b'x';
+            }
+        }
+        assert_eq!(g, Grid2::from_str_2("xxx\n01x", Some));
+        g.ray_mut(Vec2(2, 1), Direction::Left)
+            .for_each(|(_, c)| *c = b'<');
+        g.row_mut(0).rev().take(1).for_each(|(_, c)| *c = b'!');
+        assert_eq!(g, Grid2::from_str_2("xx!\n<<x", Some));
+    }
+
+    #[test]
+    fn visible_trees() {
+        let trees = Grid2::from_str_2("30373\n25512\n65332\n33549\n35390", Some);
+        let visible = trees
+            .iter()
+            .filter(|&(pos, height)| {
+                Direction::iter_all().any(|dir| trees.ray(pos, dir).all(|(_, h)| h < height))
+            })
+            .count();
+        assert_eq!(visible, 21);
+    }
+}
@@ -0,0 +1,151 @@
+use super::{Grid2, Pos};
+
+/// One of the 8 symmetries of a rectangle. Rotations are clockwise as displayed,
+/// with `y` growing downwards.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Orientation {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// Mirrored left to right
+    FlipHorizontal,
+    /// Mirrored top to bottom
+    FlipVertical,
+    /// Mirrored along the main diagonal
+    Transpose,
+    /// Mirrored along the anti-diagonal
+    AntiTranspose,
+}
+
+impl Orientation {
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::Rotate90,
+        Self::Rotate180,
+        Self::Rotate270,
+        Self::FlipHorizontal,
+        Self::FlipVertical,
+        Self::Transpose,
+        Self::AntiTranspose,
+    ];
+
+    /// Whether the transformed grid has rows and columns swapped
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Self::Rotate90 | Self::Rotate270 | Self::Transpose | Self::AntiTranspose
+        )
+    }
+
+    /// Position in a `cols` by `rows` grid which ends up at `dst` once transformed
+    fn source(self, dst: Pos, cols: usize, rows: usize) -> Pos {
+        let (x, y) = (dst.0, dst.1);
+        let (w, h) = (cols as isize - 1, rows as isize - 1);
+        let (sx, sy) = match self {
+            Self::Identity => (x, y),
+            Self::Rotate90 => (y, h - x),
+            Self::Rotate180 => (w - x, h - y),
+            Self::Rotate270 => (w - y, x),
+            Self::FlipHorizontal => (w - x, y),
+            Self::FlipVertical => (x, h - y),
+            Self::Transpose => (y, x),
+            Self::AntiTranspose => (w - y, h - x),
+        };
+        Pos::from((sx, sy))
+    }
+
+    pub fn apply<C: Clone>(self, grid: &Grid2<C>) -> Grid2<C> {
+        let (cols, rows) = if self.swaps_axes() {
+            (grid.rows, grid.cols)
+        } else {
+            (grid.cols, grid.rows)
+        };
+        let data = (0..cols * rows)
+            .map(|idx| {
+                let src = self.source(Pos::from_idx(idx, cols), grid.cols, grid.rows);
+                grid.data[src.to_idx(grid.cols)].clone()
+            })
+            .collect();
+        Grid2::from_raw(data, cols, rows)
+    }
+}
+
+impl<C: Clone> Grid2<C> {
+    /// Rotated clockwise by a quarter turn
+    pub fn rotate90(&self) -> Self {
+        Orientation::Rotate90.apply(self)
+    }
+
+    pub fn rotate180(&self) -> Self {
+        Orientation::Rotate180.apply(self)
+    }
+
+    /// Rotated counterclockwise by a quarter turn
+    pub fn rotate270(&self) -> Self {
+        Orientation::Rotate270.apply(self)
+    }
+
+    /// Mirrored left to right
+    pub fn flip_horizontal(&self) -> Self {
+        Orientation::FlipHorizontal.apply(self)
+    }
+
+    /// Mirrored top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        Orientation::FlipVertical.apply(self)
+    }
+
+    /// Rows become columns
+    pub fn transpose(&self) -> Self {
+        Orientation::Transpose.apply(self)
+    }
+}
+
+impl<C: Clone + Eq> Grid2<C> {
+    /// Every distinct orientation of the grid, in the order of [Orientation::ALL].
+    /// Symmetric grids have fewer than 8, e.g. a single cell only has one.
+    pub fn orientations(&self) -> impl Iterator<Item = (Orientation, Self)> {
+        let mut seen: Vec<(Orientation, Self)> = Vec::with_capacity(8);
+        for orientation in Orientation::ALL {
+            let grid = orientation.apply(self);
+            if !seen.iter().any(|(_, g)| *g == grid) {
+                seen.push((orientation, grid));
+            }
+        }
+        seen.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transforms() {
+        let g = Grid2::from_str_2("abc\ndef", Some);
+        assert_eq!(g.rotate90(), Grid2::from_str_2("da\neb\nfc", Some));
+        assert_eq!(g.rotate180(), Grid2::from_str_2("fed\ncba", Some));
+        assert_eq!(g.rotate270(), Grid2::from_str_2("cf\nbe\nad", Some));
+        assert_eq!(g.flip_horizontal(), Grid2::from_str_2("cba\nfed", Some));
+        assert_eq!(g.flip_vertical(), Grid2::from_str_2("def\nabc", Some));
+        assert_eq!(g.transpose(), Grid2::from_str_2("ad\nbe\ncf", Some));
+        assert_eq!(
+            Orientation::AntiTranspose.apply(&g),
+            Grid2::from_str_2("fc\neb\nda", Some)
+        );
+        assert_eq!(g.rotate90().rotate270(), g);
+    }
+
+    #[test]
+    fn symmetries() {
+        assert_eq!(Grid2::from_str_2("ab\ncd", Some).orientations().count(), 8);
+        assert_eq!(Grid2::from_str_2("##\n##", Some).orientations().count(), 1);
+        assert_eq!(Grid2::from_str_2("#.\n.#", Some).orientations().count(), 2);
+        let line: Vec<_> = Grid2::from_str_2("###", Some)
+            .orientations()
+            .map(|(o, _)| o)
+            .collect();
+        assert_eq!(line, [Orientation::Identity, Orientation::Rotate90]);
+    }
+}
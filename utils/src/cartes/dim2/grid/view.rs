@@ -0,0 +1,378 @@
+use crate::cartes::grid::{Grid, GridMut};
+
+use super::{ADJ, FULL, Grid2, Pos};
+
+/// Whether the `cols` by `rows` rectangle at `origin` lies within `grid`
+fn fits<C>(grid: &Grid2<C>, origin: Pos, cols: usize, rows: usize) -> bool {
+    origin.0 >= 0
+        && origin.1 >= 0
+        && origin.0 as usize + cols <= grid.cols
+        && origin.1 as usize + rows <= grid.rows
+}
+
+/// Borrowed rectangle of a [Grid2] with bounds `(0,0)..(cols,rows)` relative to its origin.
+///
+/// Implements [Grid] when cells are [Clone], which is only needed by [Grid::map].
+/// Cells are read only, use [Grid2ViewMut] to modify them.
+/// Views compare equal when their cells do, regardless of where they are.
+#[derive(Debug)]
+pub struct Grid2View<'g, C> {
+    grid: &'g Grid2<C>,
+    origin: Pos,
+    pub cols: usize,
+    pub rows: usize,
+}
+
+impl<C> Clone for Grid2View<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Grid2View<'_, C> {}
+
+/// Mutably borrowed rectangle of a [Grid2], see [Grid2View]
+#[derive(Debug)]
+pub struct Grid2ViewMut<'g, C> {
+    grid: &'g mut Grid2<C>,
+    origin: Pos,
+    pub cols: usize,
+    pub rows: usize,
+}
+
+impl<C> Grid2<C> {
+    /// Borrows the `cols` by `rows` rectangle at `origin`, or [None] if it does not fit
+    pub fn view(&self, origin: Pos, cols: usize, rows: usize) -> Option<Grid2View<'_, C>> {
+        fits(self, origin, cols, rows).then_some(Grid2View {
+            grid: self,
+            origin,
+            cols,
+            rows,
+        })
+    }
+
+    /// Mutably borrows the `cols` by `rows` rectangle at `origin`, or [None] if it does not fit
+    pub fn view_mut(
+        &mut self,
+        origin: Pos,
+        cols: usize,
+        rows: usize,
+    ) -> Option<Grid2ViewMut<'_, C>> {
+        fits(self, origin, cols, rows).then_some(Grid2ViewMut {
+            grid: self,
+            origin,
+            cols,
+            rows,
+        })
+    }
+
+    /// The whole grid as a view
+    pub fn as_view(&self) -> Grid2View<'_, C> {
+        Grid2View {
+            grid: self,
+            origin: Pos::zero(),
+            cols: self.cols,
+            rows: self.rows,
+        }
+    }
+
+    /// Every `cols` by `rows` rectangle of the grid, ordered by their origin row by row
+    pub fn windows(&self, cols: usize, rows: usize) -> impl Iterator<Item = Grid2View<'_, C>> {
+        self.as_view().windows(cols, rows)
+    }
+}
+
+impl<'g, C> Grid2View<'g, C> {
+    /// Position of the view's `(0,0)` in the underlying grid
+    pub fn origin(&self) -> Pos {
+        self.origin
+    }
+
+    fn index(&self, pos: Pos) -> usize {
+        (self.origin + pos).to_idx(self.grid.cols)
+    }
+
+    /// Cells of row `y`
+    pub fn row(&self, y: usize) -> &'g [C] {
+        assert!(y < self.rows, "row {y} out of bounds");
+        let start = self.index(Pos::from((0, y as isize)));
+        &self.grid.data[start..start + self.cols]
+    }
+
+    /// Cells with their position relative to the view, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &'g C)> {
+        let view = *self;
+        (0..self.rows).flat_map(move |y| {
+            view.row(y)
+                .iter()
+                .enumerate()
+                .map(move |(x, c)| (Pos::from((x as isize, y as isize)), c))
+        })
+    }
+
+    /// Borrows a rectangle of this view, see [Grid2::view]
+    pub fn view(&self, origin: Pos, cols: usize, rows: usize) -> Option<Self> {
+        let fits = origin.0 >= 0
+            && origin.1 >= 0
+            && origin.0 as usize + cols <= self.cols
+            && origin.1 as usize + rows <= self.rows;
+        fits.then_some(Self {
+            grid: self.grid,
+            origin: self.origin + origin,
+            cols,
+            rows,
+        })
+    }
+
+    /// Every `cols` by `rows` rectangle of the view, see [Grid2::windows]
+    pub fn windows(self, cols: usize, rows: usize) -> impl Iterator<Item = Self> {
+        let (xs, ys) = if cols <= self.cols && rows <= self.rows {
+            (self.cols - cols + 1, self.rows - rows + 1)
+        } else {
+            (0, 0)
+        };
+        (0..ys).flat_map(move |y| {
+            (0..xs).filter_map(move |x| self.view(Pos::from((x as isize, y as isize)), cols, rows))
+        })
+    }
+
+    /// Whether every cell matches the cell of `pattern` at the same position according to `f`.
+    /// Handy with a wildcard cell, e.g. `|p, c| p == b'.' || p == c`.
+    pub fn matches<P, F>(&self, pattern: &Grid2<P>, f: F) -> bool
+    where
+        F: Fn(&P, &C) -> bool,
+    {
+        self.cols == pattern.cols
+            && self.rows == pattern.rows
+            && self.iter().zip(&pattern.data).all(|((_, c), p)| f(p, c))
+    }
+
+    /// Copies the view into its own grid
+    pub fn to_grid(&self) -> Grid2<C>
+    where
+        C: Clone,
+    {
+        let data = (0..self.rows)
+            .flat_map(|y| self.row(y).iter().cloned())
+            .collect();
+        Grid2::from_raw(data, self.cols, self.rows)
+    }
+}
+
+impl<'g, C> Grid2ViewMut<'g, C> {
+    /// Position of the view's `(0,0)` in the underlying grid
+    pub fn origin(&self) -> Pos {
+        self.origin
+    }
+
+    fn index(&self, pos: Pos) -> usize {
+        (self.origin + pos).to_idx(self.grid.cols)
+    }
+
+    pub fn as_view(&self) -> Grid2View<'_, C> {
+        Grid2View {
+            grid: self.grid,
+            origin: self.origin,
+            cols: self.cols,
+            rows: self.rows,
+        }
+    }
+
+    /// Cells of row `y`
+    pub fn row_mut(&mut self, y: usize) -> &mut [C] {
+        assert!(y < self.rows, "row {y} out of bounds");
+        let start = self.index(Pos::from((0, y as isize)));
+        &mut self.grid.data[start..start + self.cols]
+    }
+
+    /// Sets every cell of the view to `value`
+    pub fn fill(&mut self, value: C)
+    where
+        C: Clone,
+    {
+        for y in 0..self.rows {
+            self.row_mut(y).fill(value.clone());
+        }
+    }
+}
+
+impl<C: PartialEq> PartialEq for Grid2View<'_, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cols == other.cols
+            && self.rows == other.rows
+            && (0..self.rows).all(|y| self.row(y) == other.row(y))
+    }
+}
+
+impl<C: Eq> Eq for Grid2View<'_, C> {}
+
+impl<C: PartialEq> PartialEq for Grid2ViewMut<'_, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_view() == other.as_view()
+    }
+}
+
+impl<C: Eq> Eq for Grid2ViewMut<'_, C> {}
+
+impl<'g, C> Grid for Grid2View<'g, C>
+where
+    C: Eq + Clone,
+{
+    type Pos = Pos;
+    type Cell = C;
+
+    fn contains_pos(&self, pos: Pos) -> bool {
+        (0..self.cols as isize).contains(&pos.0) && (0..self.rows as isize).contains(&pos.1)
+    }
+
+    fn get_cell_unchecked(&self, pos: Self::Pos) -> &Self::Cell {
+        &self.grid.data[self.index(pos)]
+    }
+
+    fn get_neighbours_adj_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        ADJ.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.contains_pos(*pos))
+    }
+
+    fn get_neighbours_full_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        FULL.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.contains_pos(*pos))
+    }
+
+    /// Copies the mapped cells into a new [Grid2]
+    fn map<F, T>(self, f: F) -> impl GridMut<Pos = Self::Pos, Cell = T>
+    where
+        F: Fn(Self::Cell) -> T,
+        T: Eq,
+    {
+        let data = self.iter().map(|(_, c)| f(c.clone())).collect();
+        Grid2::from_raw(data, self.cols, self.rows)
+    }
+}
+
+impl<'g, C> Grid for Grid2ViewMut<'g, C>
+where
+    C: Eq + Clone,
+{
+    type Pos = Pos;
+    type Cell = C;
+
+    fn contains_pos(&self, pos: Pos) -> bool {
+        (0..self.cols as isize).contains(&pos.0) && (0..self.rows as isize).contains(&pos.1)
+    }
+
+    fn get_cell_unchecked(&self, pos: Self::Pos) -> &Self::Cell {
+        &self.grid.data[self.index(pos)]
+    }
+
+    fn get_neighbours_adj_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        ADJ.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.contains_pos(*pos))
+    }
+
+    fn get_neighbours_full_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        FULL.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.contains_pos(*pos))
+    }
+
+    /// Copies the mapped cells into a new [Grid2]
+    fn map<F, T>(self, f: F) -> impl GridMut<Pos = Self::Pos, Cell = T>
+    where
+        F: Fn(Self::Cell) -> T,
+        T: Eq,
+    {
+        let view = self.as_view();
+        let data = view.iter().map(|(_, c)| f(c.clone())).collect();
+        Grid2::from_raw(data, self.cols, self.rows)
+    }
+}
+
+impl<'g, C> GridMut for Grid2ViewMut<'g, C>
+where
+    C: Eq + Clone,
+{
+    fn get_cell_mut_unchecked(&mut self, pos: Self::Pos) -> &mut Self::Cell {
+        let idx = self.index(pos);
+        &mut self.grid.data[idx]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartes::dim2::vec::Vec2;
+
+    #[test]
+    fn views() {
+        let g = Grid2::from_str_2("abcd\nefgh\nijkl", Some);
+        let view = g.view(Vec2(1, 1), 2, 2).unwrap();
+        assert_eq!(view.get_cell(Vec2(1, 0)), Some(&b'g'));
+        assert_eq!(view.get_cell(Vec2(2, 0)), None);
+        assert_eq!(view.get_neighbours_adj(Vec2(0, 0)).count(), 2);
+        assert_eq!(view.to_grid(), Grid2::from_str_2("fg\njk", Some));
+        assert_eq!(view.view(Vec2(1, 1), 1, 1).unwrap().row(0), b"k");
+        assert!(g.view(Vec2(3, 0), 2, 1).is_none());
+        assert!(g.view(Vec2(-1, 0), 1, 1).is_none());
+        let other = Grid2::from_str_2("xa\nye", Some);
+        assert_eq!(g.view(Vec2(0, 0), 1, 2), other.view(Vec2(1, 0), 1, 2));
+        assert_ne!(g.view(Vec2(0, 0), 1, 2), other.view(Vec2(0, 0), 1, 2));
+    }
+
+    #[test]
+    fn views_mut() {
+        let mut g = Grid2::from_str_2("....\n....\n....", Some);
+        let mut view = g.view_mut(Vec2(2, 1), 2, 2).unwrap();
+        *view.get_cell_mut(Vec2(0, 1)).unwrap() = b'x';
+        assert_eq!(view.get_cell_mut(Vec2(0, 2)), None);
+        view.row_mut(0).fill(b'#');
+        assert_eq!(g, Grid2::from_str_2("....\n..##\n..x.", Some));
+        g.view_mut(Vec2(0, 0), 1, 3).unwrap().fill(b'|');
+        assert_eq!(g, Grid2::from_str_2("|...\n|.##\n|.x.", Some));
+    }
+
+    #[test]
+    fn windows() {
+        let g = Grid2::from_str_2("abc\ndef", Some);
+        let windows: Vec<_> = g.windows(2, 2).map(|w| w.to_grid()).collect();
+        assert_eq!(
+            windows,
+            [
+                Grid2::from_str_2("ab\nde", Some),
+                Grid2::from_str_2("bc\nef", Some)
+            ]
+        );
+        assert_eq!(g.windows(3, 1).count(), 2);
+        assert_eq!(g.windows(4, 1).count(), 0);
+
+        let input = Grid2::from_str_2(
+            ".M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........",
+            Some,
+        );
+        let x_mas: Vec<_> = Grid2::from_str_2("M.S\n.A.\nM.S", Some)
+            .orientations()
+            .map(|(_, g)| g)
+            .collect();
+        let count = input
+            .windows(3, 3)
+            .filter(|w| {
+                x_mas
+                    .iter()
+                    .any(|p| w.matches(p, |p, c| *p == b'.' || p == c))
+            })
+            .count();
+        assert_eq!(count, 9);
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+
+use crate::cartes::dim2::dir::Direction;
+use crate::cartes::dim2::grid::{Grid2, Pos};
+use crate::cartes::dim2::sparse::SparseGrid2;
+use crate::cartes::dim2::vec::Vec2;
+use crate::cartes::flood::{Components, Connectivity};
+use crate::cartes::grid::Grid;
+
+/// A set of cells for measuring the shape they form.
+///
+/// Cell `(x,y)` covers the unit square between the corner points `(x,y)` and `(x+1,y+1)`,
+/// which is what [boundary][Region::boundary] is expressed in.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Region {
+    cells: SparseGrid2<()>,
+}
+
+impl Region {
+    pub fn new(cells: impl IntoIterator<Item = Pos>) -> Self {
+        cells.into_iter().collect()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_pos(pos)
+    }
+
+    /// Cells in no particular order
+    pub fn cells(&self) -> impl Iterator<Item = Pos> {
+        self.cells.positions()
+    }
+
+    /// Inclusive min and max corners of all cells, [None] if there are none
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.cells.bounds()
+    }
+
+    /// Number of cells
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Whether `pos` has an edge towards `dir` which is on the boundary
+    fn fenced(&self, pos: Pos, dir: Direction) -> bool {
+        !self.contains(pos + dir.step())
+    }
+
+    /// Number of cell edges between the region and the outside, including around holes
+    pub fn perimeter(&self) -> usize {
+        self.cells()
+            .map(|pos| {
+                Direction::iter_all()
+                    .filter(|&dir| self.fenced(pos, dir))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of straight runs of boundary edges, including around holes
+    pub fn sides(&self) -> usize {
+        self.cells()
+            .map(|pos| {
+                Direction::iter_all()
+                    .filter(|&dir| {
+                        // Only count the edge which starts a run
+                        let prev = pos + dir.rot90().step();
+                        self.fenced(pos, dir) && !(self.contains(prev) && self.fenced(prev, dir))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of corner points where the boundary turns, both convex and concave.
+    /// Matches [sides][Self::sides], as every closed rectilinear boundary has as many of both.
+    pub fn corners(&self) -> usize {
+        self.cells()
+            .map(|pos| {
+                Direction::iter_all()
+                    .filter(|&a| {
+                        let b = a.rot270();
+                        let (in_a, in_b) = (!self.fenced(pos, a), !self.fenced(pos, b));
+                        let convex = !in_a && !in_b;
+                        let concave = in_a && in_b && !self.contains(pos + a.step() + b.step());
+                        convex || concave
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of areas enclosed by the region which are not part of it.
+    /// Outside cells which only touch diagonally count as connected.
+    pub fn holes(&self) -> usize {
+        let Some((min, _)) = self.bounds() else {
+            return 0;
+        };
+        // Pad by one cell so the whole outside is one component
+        let origin = min - Vec2(1, 1);
+        let (cols, rows) = self.cells.size();
+        let (cols, rows) = (cols + 2, rows + 2);
+        let data = (0..cols * rows)
+            .map(|idx| self.contains(origin + Pos::from_idx(idx, cols)))
+            .collect();
+        let padded = Grid2::from_raw(data, cols, rows);
+        let outside = padded
+            .components(Connectivity::Full, |a, b| a == b)
+            .components
+            .iter()
+            .filter(|c| !padded[c.start])
+            .count();
+        outside - 1
+    }
+
+    /// Closed loops of the corner points where the boundary turns.
+    ///
+    /// Loops run clockwise as displayed (`y` growing downwards) around the outside,
+    /// and counterclockwise around holes, so the region is always on the right hand side.
+    /// Each loop starts at its top left point and loops are ordered by that point,
+    /// row by row. The outer boundary of a connected region therefore comes first.
+    /// Cells which only touch diagonally get separate loops.
+    pub fn boundary(&self) -> Vec<Vec<Pos>> {
+        // Steps leaving each corner point along boundary edges
+        let mut edges: HashMap<Pos, Vec<Pos>> = HashMap::new();
+        for pos in self.cells() {
+            for dir in Direction::iter_all() {
+                if !self.fenced(pos, dir) {
+                    continue;
+                }
+                let (start, step) = match dir {
+                    Direction::Up => (pos, Vec2(1, 0)),
+                    Direction::Right => (pos + Vec2(1, 0), Vec2(0, 1)),
+                    Direction::Down => (pos + Vec2(1, 1), Vec2(-1, 0)),
+                    Direction::Left => (pos + Vec2(0, 1), Vec2(0, -1)),
+                };
+                edges.entry(start).or_default().push(step);
+            }
+        }
+
+        let mut starts: Vec<Pos> = edges.keys().copied().collect();
+        starts.sort_by_key(|p| (p.1, p.0));
+        let mut loops = vec![];
+        for first in starts {
+            while let Some(initial) = edges.get_mut(&first).and_then(|steps| steps.pop()) {
+                let mut points = vec![];
+                let (mut pos, mut step) = (first + initial, initial);
+                while pos != first {
+                    // Hug the region, so loops never cross where cells touch diagonally
+                    let steps = edges.get_mut(&pos).expect("boundary is closed");
+                    let (right, left) = (Vec2(-step.1, step.0), Vec2(step.1, -step.0));
+                    let idx = [right, step, left]
+                        .iter()
+                        .find_map(|s| steps.iter().position(|t| t == s))
+                        .expect("boundary is closed");
+                    let next = steps.swap_remove(idx);
+                    if next != step {
+                        points.push(pos);
+                    }
+                    step = next;
+                    pos += step;
+                }
+                points.insert(0, first);
+                loops.push(points);
+            }
+        }
+        loops
+    }
+}
+
+impl FromIterator<Pos> for Region {
+    fn from_iter<I: IntoIterator<Item = Pos>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().map(|pos| (pos, ())).collect(),
+        }
+    }
+}
+
+impl<L> Components<L, Pos>
+where
+    L: Grid<Pos = Pos, Cell = Option<usize>>,
+{
+    /// The cells of the component with index `label`
+    pub fn region(&self, label: usize) -> Region {
+        let (min, max) = self.components[label].bounds;
+        (min.1..=max.1)
+            .flat_map(|y| (min.0..=max.0).map(move |x| Vec2(x, y)))
+            .filter(|&pos| self.label(pos) == Some(label))
+            .collect()
+    }
+
+    /// The cells of every component, in label order
+    pub fn regions(&self) -> impl Iterator<Item = Region> {
+        (0..self.components.len()).map(|label| self.region(label))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(s: &str) -> Region {
+        Grid2::from_str_2(s, Some)
+            .iter()
+            .filter(|&(_, &c)| c == b'#')
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    #[test]
+    fn measures() {
+        let square = region("##\n##");
+        assert_eq!(
+            (
+                square.area(),
+                square.perimeter(),
+                square.sides(),
+                square.corners()
+            ),
+            (4, 8, 4, 4)
+        );
+
+        let e = region("#####\n#....\n#####\n#....\n#####");
+        assert_eq!(
+            (e.area(), e.perimeter(), e.sides(), e.holes()),
+            (17, 36, 12, 0)
+        );
+        assert_eq!(e.corners(), 12);
+
+        let ring = region("###\n#.#\n###");
+        assert_eq!((ring.perimeter(), ring.sides(), ring.holes()), (16, 8, 1));
+
+        let pinched = region("##.\n#.#\n.##");
+        assert_eq!(
+            (pinched.sides(), pinched.corners(), pinched.holes()),
+            (12, 12, 0)
+        );
+
+        let empty = Region::default();
+        assert_eq!((empty.perimeter(), empty.holes()), (0, 0));
+        assert!(empty.boundary().is_empty());
+    }
+
+    #[test]
+    fn boundary() {
+        let ring = region("###\n#.#\n###");
+        assert_eq!(
+            ring.boundary(),
+            [
+                vec![Vec2(0, 0), Vec2(3, 0), Vec2(3, 3), Vec2(0, 3)],
+                vec![Vec2(1, 1), Vec2(1, 2), Vec2(2, 2), Vec2(2, 1)],
+            ]
+        );
+
+        let ell = region("#.\n##");
+        assert_eq!(
+            ell.boundary(),
+            [vec![
+                Vec2(0, 0),
+                Vec2(1, 0),
+                Vec2(1, 1),
+                Vec2(2, 1),
+                Vec2(2, 2),
+                Vec2(0, 2)
+            ]]
+        );
+
+        let diagonal = region("#.\n.#");
+        let loops = diagonal.boundary();
+        assert_eq!(loops.len(), 2);
+        assert!(loops.iter().all(|l| l.len() == 4));
+        let corners: usize = loops.iter().map(Vec::len).sum();
+        assert_eq!(corners, diagonal.sides());
+    }
+
+    #[test]
+    fn components() {
+        let grid = Grid2::from_str_2("AAB\nABB\nCCB", Some);
+        let components = grid.components(Connectivity::Adjacent, |a, b| a == b);
+        let regions: Vec<_> = components.regions().collect();
+        assert_eq!(regions.len(), 3);
+        assert_eq!(
+            regions[1],
+            Region::new([Vec2(2, 0), Vec2(1, 1), Vec2(2, 1), Vec2(2, 2)])
+        );
+        assert_eq!(
+            regions[2].boundary()[0],
+            [Vec2(0, 2), Vec2(2, 2), Vec2(2, 3), Vec2(0, 3)]
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use crate::cartes::dim2::grid::{ADJ, FULL, Grid2, Pos};
+use crate::cartes::grid::{Grid, GridMut};
+
+/// Unbounded 2-dimensional grid which only stores the cells that were set.
+///
+/// A position is in the grid when it holds a cell, positions are not limited to a range.
+/// The bounding box of all cells is kept up to date as cells come and go.
+#[derive(Debug, Clone)]
+pub struct SparseGrid2<C> {
+    cells: HashMap<Pos, C>,
+    /// Inclusive min and max corners of all cells
+    bounds: Option<(Pos, Pos)>,
+}
+
+impl<C> Default for SparseGrid2<C> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<C> SparseGrid2<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Sets the cell at `pos`, returning the previous one
+    pub fn insert(&mut self, pos: Pos, cell: C) -> Option<C> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Pos::from((min.0.min(pos.0), min.1.min(pos.1))),
+                Pos::from((max.0.max(pos.0), max.1.max(pos.1))),
+            ),
+            None => (pos, pos),
+        });
+        self.cells.insert(pos, cell)
+    }
+
+    /// Removes the cell at `pos`. Shrinks the bounding box if `pos` was on its edge,
+    /// which takes time proportional to the number of cells.
+    pub fn remove(&mut self, pos: Pos) -> Option<C> {
+        let cell = self.cells.remove(&pos)?;
+        if let Some((min, max)) = self.bounds
+            && (pos.0 == min.0 || pos.0 == max.0 || pos.1 == min.1 || pos.1 == max.1)
+        {
+            self.bounds = Self::bounds_of(self.cells.keys().copied());
+        }
+        Some(cell)
+    }
+
+    fn bounds_of(positions: impl Iterator<Item = Pos>) -> Option<(Pos, Pos)> {
+        positions.fold(None, |bounds, pos| {
+            Some(match bounds {
+                Some((min, max)) => (
+                    Pos::from((pos.0.min(min.0), pos.1.min(min.1))),
+                    Pos::from((pos.0.max(max.0), pos.1.max(max.1))),
+                ),
+                None => (pos, pos),
+            })
+        })
+    }
+
+    /// Removes every cell, keeping the allocated capacity
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    /// Inclusive min and max corners of all cells, [None] if there are none
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.bounds
+    }
+
+    /// Width and height of the bounding box
+    pub fn size(&self) -> (usize, usize) {
+        self.bounds.map_or((0, 0), |(min, max)| {
+            ((max.0 - min.0 + 1) as usize, (max.1 - min.1 + 1) as usize)
+        })
+    }
+
+    /// Cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &C)> {
+        self.cells.iter().map(|(pos, c)| (*pos, c))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut C)> {
+        self.cells.iter_mut().map(|(pos, c)| (*pos, c))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        self.cells.keys().copied()
+    }
+
+    /// The bounding box as a dense grid, whose `(0,0)` is the min corner of
+    /// [bounds][Self::bounds]. `f` maps every position of the box to a dense cell.
+    pub fn densify_with<F, T>(&self, f: F) -> Grid2<T>
+    where
+        F: Fn(Option<&C>) -> T,
+    {
+        let Some((min, _)) = self.bounds else {
+            return Grid2::from_raw(vec![], 0, 0);
+        };
+        let (cols, rows) = self.size();
+        let data = (0..cols * rows)
+            .map(|idx| f(self.cells.get(&(min + Pos::from_idx(idx, cols)))))
+            .collect();
+        Grid2::from_raw(data, cols, rows)
+    }
+
+    /// The bounding box as a dense grid with missing cells set to `fill`,
+    /// see [densify_with][Self::densify_with]
+    pub fn densify(&self, fill: C) -> Grid2<C>
+    where
+        C: Clone,
+    {
+        self.densify_with(|c| c.unwrap_or(&fill).clone())
+    }
+
+    /// The cells of `grid` for which `keep` holds
+    pub fn from_grid<F>(grid: &Grid2<C>, keep: F) -> Self
+    where
+        C: Clone + Eq,
+        F: Fn(&C) -> bool,
+    {
+        grid.iter()
+            .filter(|(_, c)| keep(c))
+            .map(|(pos, c)| (pos, c.clone()))
+            .collect()
+    }
+}
+
+impl<C> FromIterator<(Pos, C)> for SparseGrid2<C> {
+    fn from_iter<I: IntoIterator<Item = (Pos, C)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, cell) in iter {
+            grid.insert(pos, cell);
+        }
+        grid
+    }
+}
+
+impl<C> Extend<(Pos, C)> for SparseGrid2<C> {
+    fn extend<I: IntoIterator<Item = (Pos, C)>>(&mut self, iter: I) {
+        for (pos, cell) in iter {
+            self.insert(pos, cell);
+        }
+    }
+}
+
+impl<C: PartialEq> PartialEq for SparseGrid2<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<C: Eq> Eq for SparseGrid2<C> {}
+
+impl<C> Grid for SparseGrid2<C>
+where
+    C: Eq,
+{
+    type Pos = Pos;
+    type Cell = C;
+
+    fn contains_pos(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    fn get_cell(&self, pos: Self::Pos) -> Option<&Self::Cell> {
+        self.cells.get(&pos)
+    }
+
+    fn get_cell_unchecked(&self, pos: Self::Pos) -> &Self::Cell {
+        &self.cells[&pos]
+    }
+
+    fn get_neighbours_adj_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        ADJ.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.contains_pos(*pos))
+    }
+
+    fn get_neighbours_full_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        FULL.into_iter()
+            .map(move |d| pos + d)
+            .filter(|pos| self.contains_pos(*pos))
+    }
+
+    fn map<F, T>(self, f: F) -> impl GridMut<Pos = Self::Pos, Cell = T>
+    where
+        F: Fn(Self::Cell) -> T,
+        T: Eq,
+    {
+        SparseGrid2 {
+            cells: self.cells.into_iter().map(|(pos, c)| (pos, f(c))).collect(),
+            bounds: self.bounds,
+        }
+    }
+}
+
+impl<C> GridMut for SparseGrid2<C>
+where
+    C: Eq,
+{
+    fn get_cell_mut(&mut self, pos: Self::Pos) -> Option<&mut Self::Cell> {
+        self.cells.get_mut(&pos)
+    }
+
+    fn get_cell_mut_unchecked(&mut self, pos: Self::Pos) -> &mut Self::Cell {
+        self.cells.get_mut(&pos).expect("no cell at position")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartes::dim2::vec::Vec2;
+
+    #[test]
+    fn bounds() {
+        let mut grid = SparseGrid2::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Vec2(2, -3), 'a');
+        grid.insert(Vec2(-1, 0), 'b');
+        grid.insert(Vec2(0, 5), 'c');
+        assert_eq!(grid.bounds(), Some((Vec2(-1, -3), Vec2(2, 5))));
+        assert_eq!(grid.size(), (4, 9));
+
+        assert_eq!(grid.remove(Vec2(0, 5)), Some('c'));
+        assert_eq!(grid.remove(Vec2(0, 5)), None);
+        assert_eq!(grid.bounds(), Some((Vec2(-1, -3), Vec2(2, 0))));
+        grid.remove(Vec2(2, -3));
+        grid.remove(Vec2(-1, 0));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn grid() {
+        let mut grid: SparseGrid2<u8> = [(Vec2(0, 0), 1), (Vec2(1, 0), 2), (Vec2(1, 1), 3)]
+            .into_iter()
+            .collect();
+        assert!(grid.contains_pos(Vec2(1, 1)));
+        assert!(!grid.contains_pos(Vec2(0, 1)));
+        assert_eq!(grid.get_neighbours_adj(Vec2(1, 0)).sum::<u8>(), 4);
+        assert_eq!(grid.get_neighbours_full_pos(Vec2(0, 1)).count(), 3);
+        *grid.get_cell_mut(Vec2(0, 0)).unwrap() = 7;
+        assert_eq!(grid.get_cell_mut(Vec2(5, 5)), None);
+        assert_eq!(grid.get_cell(Vec2(0, 0)), Some(&7));
+    }
+
+    #[test]
+    fn densify() {
+        let rock: SparseGrid2<char> = [(-1, 2), (1, 2), (1, 3)]
+            .into_iter()
+            .map(|p| (Vec2::from(p), '#'))
+            .collect();
+        let dense = rock.densify('.');
+        assert_eq!((dense.cols, dense.rows), (3, 2));
+        assert_eq!(dense.as_slice(), ['#', '.', '#', '.', '.', '#']);
+        assert_eq!(SparseGrid2::from_grid(&dense, |&c| c == '#'), {
+            let mut shifted = SparseGrid2::new();
+            shifted.extend(rock.iter().map(|(p, &c)| (p - Vec2(-1, 2), c)));
+            shifted
+        });
+        let empty = SparseGrid2::<char>::new().densify_with(|c| c.is_some());
+        assert_eq!((empty.cols, empty.rows), (0, 0));
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+
+use crate::cartes::dim2::grid::{ADJ, FULL, Grid2, Pos};
+use crate::cartes::grid::{Grid, GridMut};
+
+/// Position of the base grid cell which `pos` lands on when the grid repeats in every direction
+fn wrap<C>(grid: &Grid2<C>, pos: Pos) -> Pos {
+    pos.bounds_wrap(
+        Pos::zero(),
+        Pos::from((grid.cols as isize, grid.rows as isize)),
+    )
+}
+
+/// A [Grid2] whose opposite edges are connected.
+///
+/// Every position maps to a cell, and neighbours are always reported wrapped back into
+/// `(0,0)..(cols,rows)`. This makes pathing algorithms walk across the edges.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Torus2<C> {
+    grid: Grid2<C>,
+}
+
+impl<C> Torus2<C> {
+    pub fn new(grid: Grid2<C>) -> Self {
+        Self { grid }
+    }
+
+    pub fn into_inner(self) -> Grid2<C> {
+        self.grid
+    }
+
+    pub fn as_grid(&self) -> &Grid2<C> {
+        &self.grid
+    }
+
+    /// `pos` wrapped into the bounds of the underlying grid
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        wrap(&self.grid, pos)
+    }
+}
+
+impl<C> Grid for Torus2<C>
+where
+    C: Eq,
+{
+    type Pos = Pos;
+    type Cell = C;
+
+    /// Any position as long as the grid is not empty
+    fn contains_pos(&self, _: Pos) -> bool {
+        !self.grid.data.is_empty()
+    }
+
+    fn get_cell_unchecked(&self, pos: Self::Pos) -> &Self::Cell {
+        &self.grid[self.wrap(pos)]
+    }
+
+    fn get_neighbours_adj_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        ADJ.into_iter()
+            .filter(move |_| self.contains_pos(pos))
+            .map(move |d| self.wrap(pos + d))
+    }
+
+    fn get_neighbours_full_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        FULL.into_iter()
+            .filter(move |_| self.contains_pos(pos))
+            .map(move |d| self.wrap(pos + d))
+    }
+
+    /// Taxicab distance, going across the edges where that is shorter
+    fn distance(&self, from: Self::Pos, to: Self::Pos) -> isize {
+        let d = self.wrap(to - from);
+        let (cols, rows) = (self.grid.cols as isize, self.grid.rows as isize);
+        d.0.min(cols - d.0) + d.1.min(rows - d.1)
+    }
+
+    fn map<F, T>(self, f: F) -> impl GridMut<Pos = Self::Pos, Cell = T>
+    where
+        F: Fn(Self::Cell) -> T,
+        T: Eq,
+    {
+        let Grid2 { data, cols, rows } = self.grid;
+        Torus2::new(Grid2::from_raw(
+            data.into_iter().map(f).collect(),
+            cols,
+            rows,
+        ))
+    }
+}
+
+impl<C> GridMut for Torus2<C>
+where
+    C: Eq,
+{
+    fn get_cell_mut_unchecked(&mut self, pos: Self::Pos) -> &mut Self::Cell {
+        let pos = self.wrap(pos);
+        &mut self.grid[pos]
+    }
+}
+
+/// A [Grid2] repeated infinitely in every direction.
+///
+/// Unlike [Torus2], positions are never wrapped: `(cols,0)` is a neighbour of `(cols-1,0)`
+/// and a different position from `(0,0)`, even though both read the same base cell.
+/// Mutating a cell only changes that one copy, which is stored separately from the base grid.
+///
+/// Pathing on a tiled grid never ends when the target is unreachable.
+#[derive(Debug, Clone)]
+pub struct Tiled2<C, S = C, F = fn(&C) -> C> {
+    /// The base grid before any [map][Grid::map]s, kept to create fresh copies of mapped cells
+    source: Grid2<S>,
+    /// Creates the cell of a copy from the source cell
+    fresh: F,
+    tile: Grid2<C>,
+    /// Cells which were mutated, by their unwrapped position
+    copies: HashMap<Pos, C>,
+}
+
+impl<C: Clone> Tiled2<C> {
+    pub fn new(grid: Grid2<C>) -> Self {
+        Self {
+            source: grid.clone(),
+            fresh: C::clone,
+            tile: grid,
+            copies: HashMap::new(),
+        }
+    }
+}
+
+impl<C, S, F> Tiled2<C, S, F> {
+    /// The repeated grid, without any mutations
+    pub fn tile(&self) -> &Grid2<C> {
+        &self.tile
+    }
+
+    /// `pos` wrapped into the bounds of the base grid
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        wrap(&self.tile, pos)
+    }
+
+    /// The positions of every mutated cell
+    pub fn mutated(&self) -> impl Iterator<Item = Pos> {
+        self.copies.keys().copied()
+    }
+}
+
+impl<C: PartialEq, S, F> PartialEq for Tiled2<C, S, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tile == other.tile && self.copies == other.copies
+    }
+}
+
+impl<C: Eq, S, F> Eq for Tiled2<C, S, F> {}
+
+impl<C, S, F> Grid for Tiled2<C, S, F>
+where
+    C: Eq,
+    F: Fn(&S) -> C,
+{
+    type Pos = Pos;
+    type Cell = C;
+
+    /// Any position as long as the base grid is not empty
+    fn contains_pos(&self, _: Pos) -> bool {
+        !self.tile.data.is_empty()
+    }
+
+    fn get_cell_unchecked(&self, pos: Self::Pos) -> &Self::Cell {
+        self.copies
+            .get(&pos)
+            .unwrap_or_else(|| &self.tile[self.wrap(pos)])
+    }
+
+    fn get_neighbours_adj_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        ADJ.into_iter()
+            .filter(move |_| self.contains_pos(pos))
+            .map(move |d| pos + d)
+    }
+
+    fn get_neighbours_full_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos> {
+        FULL.into_iter()
+            .filter(move |_| self.contains_pos(pos))
+            .map(move |d| pos + d)
+    }
+
+    fn map<G, T>(self, f: G) -> impl GridMut<Pos = Self::Pos, Cell = T>
+    where
+        G: Fn(Self::Cell) -> T,
+        T: Eq,
+    {
+        let Grid2 { data, cols, rows } = self.tile;
+        let tile = Grid2::from_raw(data.into_iter().map(&f).collect(), cols, rows);
+        let copies = self.copies.into_iter().map(|(p, c)| (p, f(c))).collect();
+        let fresh = self.fresh;
+        Tiled2 {
+            source: self.source,
+            fresh: move |s: &S| f(fresh(s)),
+            tile,
+            copies,
+        }
+    }
+}
+
+impl<C, S, F> GridMut for Tiled2<C, S, F>
+where
+    C: Eq,
+    F: Fn(&S) -> C,
+{
+    fn get_cell_mut_unchecked(&mut self, pos: Self::Pos) -> &mut Self::Cell {
+        let base = self.wrap(pos);
+        self.copies
+            .entry(pos)
+            .or_insert_with(|| (self.fresh)(&self.source.data[base.to_idx(self.source.cols)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartes::dim2::vec::Vec2;
+    use crate::cartes::pathing::Pathable;
+    use crate::cartes::pathing::astar::astar_oneshot;
+    use crate::cartes::pathing::dijkstra::dijkstra_oneshot;
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct Tile(u8);
+
+    impl Pathable for Tile {
+        fn can_pass(&self) -> bool {
+            self.0 == b'.'
+        }
+    }
+
+    fn grid(s: &str) -> Grid2<Tile> {
+        Grid2::from_str_2(s, |c| Some(Tile(c)))
+    }
+
+    const MAZE: &str = ".#.
+.#.
+...";
+
+    #[test]
+    fn wrapping() {
+        let torus = Torus2::new(grid(MAZE));
+        assert_eq!(torus.get_cell(Vec2(-2, 4)), Some(&Tile(b'#')));
+        assert_eq!(torus.get_cell(Vec2(3, -1)), Some(&Tile(b'.')));
+        let mut adj: Vec<_> = torus.get_neighbours_adj_pos(Vec2(0, 0)).collect();
+        adj.sort_by_key(|p| (p.1, p.0));
+        assert_eq!(adj, [Vec2(1, 0), Vec2(2, 0), Vec2(0, 1), Vec2(0, 2)]);
+        assert_eq!(torus.get_neighbours_full_pos(Vec2(2, 2)).count(), 8);
+        assert!(!Torus2::new(Grid2::<Tile>::from_raw(vec![], 0, 0)).contains_pos(Vec2(0, 0)));
+
+        let path = dijkstra_oneshot(&torus, Vec2(0, 0), Vec2(2, 0)).unwrap();
+        assert_eq!(path.steps, 2);
+        assert_eq!(path.iter.collect::<Vec<_>>(), [Vec2(0, 0), Vec2(2, 0)]);
+        let flat = dijkstra_oneshot(torus.as_grid(), Vec2(0, 0), Vec2(2, 0)).unwrap();
+        assert_eq!(flat.steps, 7);
+    }
+
+    #[test]
+    fn astar_across_edges() {
+        let ring = Torus2::new(grid("......."));
+        assert_eq!(ring.distance(Vec2(1, 0), Vec2(5, 0)), 3);
+        assert_eq!(ring.distance(Vec2(1, 0), Vec2(3, 0)), 2);
+        let path = astar_oneshot(&ring, Vec2(1, 0), Vec2(5, 0)).unwrap();
+        assert_eq!(path.steps, 4);
+        assert_eq!(
+            path.iter.collect::<Vec<_>>(),
+            [Vec2(1, 0), Vec2(0, 0), Vec2(6, 0), Vec2(5, 0)]
+        );
+        let path = dijkstra_oneshot(&ring, Vec2(1, 0), Vec2(5, 0)).unwrap();
+        assert_eq!(path.steps, 4);
+
+        let path = astar_oneshot(&Torus2::new(grid(MAZE)), Vec2(0, 0), Vec2(2, 0)).unwrap();
+        assert_eq!(path.steps, 2);
+    }
+
+    #[test]
+    fn tiled() {
+        let mut tiled = Tiled2::new(grid(MAZE));
+        assert_eq!(tiled.get_cell(Vec2(4, -3)), Some(&Tile(b'#')));
+        assert_eq!(tiled.get_neighbours_adj_pos(Vec2(2, 0)).count(), 4);
+        assert!(
+            tiled
+                .get_neighbours_adj_pos(Vec2(2, 0))
+                .any(|p| p == Vec2(3, 0))
+        );
+
+        *tiled.get_cell_mut(Vec2(3, 0)).unwrap() = Tile(b'#');
+        assert_eq!(tiled.get_cell(Vec2(3, 0)), Some(&Tile(b'#')));
+        assert_eq!(tiled.get_cell(Vec2(0, 0)), Some(&Tile(b'.')));
+        assert_eq!(tiled.mutated().collect::<Vec<_>>(), [Vec2(3, 0)]);
+        *tiled.get_cell_mut(Vec2(3, 0)).unwrap() = Tile(b'.');
+
+        // Around the wall through the tile above
+        let path = dijkstra_oneshot(&tiled, Vec2(0, 0), Vec2(3, 0)).unwrap();
+        assert_eq!(path.steps, 6);
+        let path = astar_oneshot(&tiled, Vec2(0, 0), Vec2(-3, 3)).unwrap();
+        assert_eq!(path.steps, 7);
+    }
+}
@@ -0,0 +1,264 @@
+use super::dim2::grid::{Grid2, Pos as Pos2};
+use super::grid::{Grid, GridMut};
+use super::pos::Pos;
+
+/// Which neighbours of a cell it is connected to
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Connectivity {
+    /// Only the 4 orthogonal neighbours
+    #[default]
+    Adjacent,
+    /// All 8 neighbours, including diagonals
+    Full,
+}
+
+impl Connectivity {
+    fn neighbours<G: Grid>(self, grid: &G, pos: G::Pos) -> impl Iterator<Item = G::Pos> {
+        let adj = (self == Self::Adjacent).then(|| grid.get_neighbours_adj_pos(pos));
+        let full = (self == Self::Full).then(|| grid.get_neighbours_full_pos(pos));
+        adj.into_iter().flatten().chain(full.into_iter().flatten())
+    }
+}
+
+/// Marks every cell reachable from `start` with `label` breadth first, calling `visit` on each.
+/// Cells which are already marked are never entered.
+fn spread<G, L, F>(
+    grid: &G,
+    marks: &mut L,
+    start: G::Pos,
+    connectivity: Connectivity,
+    same: &F,
+    label: usize,
+    mut visit: impl FnMut(G::Pos),
+) where
+    G: Grid,
+    L: GridMut<Pos = G::Pos, Cell = Option<usize>>,
+    F: Fn(&G::Cell, &G::Cell) -> bool,
+{
+    *marks.get_cell_mut_unchecked(start) = Some(label);
+    let mut open = std::collections::VecDeque::from([start]);
+    while let Some(pos) = open.pop_front() {
+        visit(pos);
+        let cell = grid.get_cell_unchecked(pos);
+        for next in connectivity.neighbours(grid, pos) {
+            if marks.get_cell_unchecked(next).is_none() && same(cell, grid.get_cell_unchecked(next))
+            {
+                *marks.get_cell_mut_unchecked(next) = Some(label);
+                open.push_back(next);
+            }
+        }
+    }
+}
+
+/// Positions of every cell reachable from `start`, nearest first.
+///
+/// A neighbour is entered when `same(cell, neighbour)` holds.
+/// Returns nothing when `start` is out-of-bounds.
+pub fn flood_fill<G, F>(grid: &G, start: G::Pos, connectivity: Connectivity, same: F) -> Vec<G::Pos>
+where
+    G: Grid + Clone,
+    F: Fn(&G::Cell, &G::Cell) -> bool,
+{
+    if !grid.contains_pos(start) {
+        return vec![];
+    }
+    let mut marks = grid.clone().map(|_| None);
+    let mut filled = vec![];
+    spread(grid, &mut marks, start, connectivity, &same, 0, |pos| {
+        filled.push(pos)
+    });
+    filled
+}
+
+/// A connected region of cells found by [label_components]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Component<P> {
+    /// The position the component was discovered from
+    pub start: P,
+    /// Number of cells
+    pub size: usize,
+    /// Inclusive min and max corners of the bounding box
+    pub bounds: (P, P),
+}
+
+/// Output of [label_components].
+/// `labels` holds the index into `components` of every labeled cell.
+#[derive(Debug, Clone)]
+pub struct Components<L, P> {
+    pub labels: L,
+    pub components: Vec<Component<P>>,
+}
+
+impl<L, P> Components<L, P>
+where
+    L: Grid<Pos = P, Cell = Option<usize>>,
+    P: Pos,
+{
+    /// Index of the component containing `pos`
+    pub fn label(&self, pos: P) -> Option<usize> {
+        self.labels.get_cell(pos).copied().flatten()
+    }
+
+    /// The component containing `pos`
+    pub fn component(&self, pos: P) -> Option<&Component<P>> {
+        self.label(pos).map(|label| &self.components[label])
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+/// Splits the cells at `positions` into connected components.
+///
+/// Two neighbouring cells belong to the same component when `same(cell, neighbour)` holds,
+/// which should be symmetric. Components are labeled in order of discovery,
+/// so the first position always gets label `0`. Cells not reachable from any of
+/// `positions` stay unlabeled.
+pub fn label_components<G, I, F>(
+    grid: &G,
+    positions: I,
+    connectivity: Connectivity,
+    same: F,
+) -> Components<impl Grid<Pos = G::Pos, Cell = Option<usize>> + use<G, I, F>, G::Pos>
+where
+    G: Grid + Clone,
+    I: IntoIterator<Item = G::Pos>,
+    F: Fn(&G::Cell, &G::Cell) -> bool,
+{
+    let mut labels = grid.clone().map(|_| None);
+    let mut components: Vec<Component<G::Pos>> = vec![];
+    for start in positions {
+        if !grid.contains_pos(start) || labels.get_cell_unchecked(start).is_some() {
+            continue;
+        }
+        let mut component = Component {
+            start,
+            size: 0,
+            bounds: (start, start),
+        };
+        let label = components.len();
+        spread(
+            grid,
+            &mut labels,
+            start,
+            connectivity,
+            &same,
+            label,
+            |pos| {
+                component.size += 1;
+                component.bounds = (
+                    component.bounds.0.min_axes(pos),
+                    component.bounds.1.max_axes(pos),
+                );
+            },
+        );
+        components.push(component);
+    }
+    Components { labels, components }
+}
+
+impl<C: Eq + Clone> Grid2<C> {
+    /// Labels every cell with its connected component, scanning row by row.
+    /// See [label_components].
+    pub fn components<F>(
+        &self,
+        connectivity: Connectivity,
+        same: F,
+    ) -> Components<impl Grid<Pos = Pos2, Cell = Option<usize>> + use<C, F>, Pos2>
+    where
+        F: Fn(&C, &C) -> bool,
+    {
+        let cols = self.cols;
+        let positions = (0..self.data.len()).map(move |idx| Pos2::from_idx(idx, cols));
+        label_components(self, positions, connectivity, same)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cartes::dim2::sparse::SparseGrid2;
+    use crate::cartes::dim2::vec::Vec2;
+
+    const GARDEN: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn regions() {
+        let garden = Grid2::from_str_2(GARDEN, Some);
+        let regions = garden.components(Connectivity::Adjacent, |a, b| a == b);
+        assert_eq!(regions.len(), 11);
+
+        let r = regions.component(Vec2(0, 0)).unwrap();
+        assert_eq!(
+            (r.start, r.size, r.bounds),
+            (Vec2(0, 0), 12, (Vec2(0, 0), Vec2(4, 3)))
+        );
+        let c = regions.component(Vec2(7, 4)).unwrap();
+        assert_eq!((c.size, c.bounds), (1, (Vec2(7, 4), Vec2(7, 4))));
+        assert_eq!(regions.label(Vec2(6, 0)), regions.label(Vec2(3, 3)));
+        assert_eq!(regions.label(Vec2(10, 0)), None);
+
+        let price: usize = garden
+            .iter()
+            .map(|(pos, _)| {
+                let label = regions.label(pos);
+                let inner = garden
+                    .get_neighbours_adj_pos(pos)
+                    .filter(|&n| regions.label(n) == label)
+                    .count();
+                (4 - inner) * regions.component(pos).unwrap().size
+            })
+            .sum();
+        assert_eq!(price, 1930);
+    }
+
+    #[test]
+    fn fill() {
+        let grid = Grid2::from_str_2("#..\n.#.\n..#", Some);
+        let same = |a: &u8, b: &u8| a == b;
+        assert_eq!(
+            flood_fill(&grid, Vec2(0, 0), Connectivity::Adjacent, same).len(),
+            1
+        );
+        let diagonal = flood_fill(&grid, Vec2(0, 0), Connectivity::Full, same);
+        assert_eq!(diagonal, [Vec2(0, 0), Vec2(1, 1), Vec2(2, 2)]);
+        assert_eq!(grid.components(Connectivity::Adjacent, same).len(), 5);
+        assert_eq!(grid.components(Connectivity::Full, same).len(), 2);
+        assert!(flood_fill(&grid, Vec2(3, 0), Connectivity::Full, same).is_empty());
+
+        // Gradients, e.g. steps which only climb by one
+        let hills = Grid2::from_str_2("0123\n1254", Some);
+        let climb = flood_fill(&hills, Vec2(0, 0), Connectivity::Adjacent, |a, b| {
+            b == &(a + 1)
+        });
+        assert_eq!(climb.len(), 8);
+    }
+
+    #[test]
+    fn sparse() {
+        let rocks: SparseGrid2<()> = [(0, 0), (1, 0), (5, 5), (6, 6), (9, 9)]
+            .into_iter()
+            .map(|p| (Vec2::from(p), ()))
+            .collect();
+        let mut starts: Vec<_> = rocks.positions().collect();
+        starts.sort_by_key(|p| (p.1, p.0));
+        let groups = label_components(&rocks, starts, Connectivity::Full, |_, _| true);
+        let sizes: Vec<_> = groups.components.iter().map(|c| c.size).collect();
+        assert_eq!(sizes, [2, 2, 1]);
+        assert_eq!(groups.components[1].bounds, (Vec2(5, 5), Vec2(6, 6)));
+    }
+}
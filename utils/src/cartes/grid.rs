@@ -1,6 +1,8 @@
 use super::pos::Pos;
 
-/// Represents cartesian-coordinate structures that utilizes integer coordinates
+/// Represents cartesian-coordinate structures that utilizes integer coordinates.
+///
+/// Only reads cells, see [GridMut] for grids whose cells can be borrowed mutably.
 pub trait Grid: Eq {
     /// [Pos] should be cheap to [Copy] as it will be used for referencing very often
     type Pos: Pos;
@@ -22,20 +24,14 @@ pub trait Grid: Eq {
 
     fn get_cell_unchecked(&self, pos: Self::Pos) -> &Self::Cell;
 
-    /// If [pos] is out-of-bounds then this method returns [None]
-    fn get_cell_mut(&mut self, pos: Self::Pos) -> Option<&mut Self::Cell> {
-        if !self.contains_pos(pos) {
-            None
-        } else {
-            Some(self.get_cell_mut_unchecked(pos))
-        }
-    }
-
-    fn get_cell_mut_unchecked(&mut self, pos: Self::Pos) -> &mut Self::Cell;
-
     /// Returns the direct neighbours of a cell.
     /// Should NOT include diagonal neighbours.
-    fn get_neighbours_adj(&self, pos: Self::Pos) -> impl Iterator<Item = &Self::Cell>;
+    ///
+    /// Defaults to the cells at [get_neighbours_adj_pos][Grid::get_neighbours_adj_pos].
+    fn get_neighbours_adj(&self, pos: Self::Pos) -> impl Iterator<Item = &Self::Cell> {
+        self.get_neighbours_adj_pos(pos)
+            .map(|pos| self.get_cell_unchecked(pos))
+    }
 
     /// Returns the direct neighbours of a cell.
     /// Should NOT include diagonal neighbours.
@@ -43,15 +39,44 @@ pub trait Grid: Eq {
 
     /// Returns the direct neighbours of a cell.
     /// MUST include diagonal neighbours.
-    fn get_neighbours_full(&self, pos: Self::Pos) -> impl Iterator<Item = &Self::Cell>;
+    ///
+    /// Defaults to the cells at [get_neighbours_full_pos][Grid::get_neighbours_full_pos].
+    fn get_neighbours_full(&self, pos: Self::Pos) -> impl Iterator<Item = &Self::Cell> {
+        self.get_neighbours_full_pos(pos)
+            .map(|pos| self.get_cell_unchecked(pos))
+    }
 
     /// Returns the direct neighbours of a cell.
     /// MUST include diagonal neighbours.
     fn get_neighbours_full_pos(&self, pos: Self::Pos) -> impl Iterator<Item = Self::Pos>;
 
+    /// Fewest adjacent steps from `from` to `to` if nothing was in the way.
+    /// Used as the heuristic for pathing, so it must never overestimate.
+    ///
+    /// Defaults to the [taxicab distance][Pos::taxicab_dst].
+    fn distance(&self, from: Self::Pos, to: Self::Pos) -> <Self::Pos as Pos>::N {
+        from.taxicab_dst(to)
+    }
+
     /// Maps cells with a function. Retains the same [Pos][Grid::Pos] type.
-    fn map<F, T>(self, f: F) -> impl Grid<Pos = Self::Pos, Cell = T>
+    ///
+    /// The result is always mutable, even if `self` is not.
+    fn map<F, T>(self, f: F) -> impl GridMut<Pos = Self::Pos, Cell = T>
     where
         F: Fn(Self::Cell) -> T,
         T: Eq;
 }
+
+/// A [Grid] whose cells can be borrowed mutably
+pub trait GridMut: Grid {
+    /// If [pos] is out-of-bounds then this method returns [None]
+    fn get_cell_mut(&mut self, pos: Self::Pos) -> Option<&mut Self::Cell> {
+        if !self.contains_pos(pos) {
+            None
+        } else {
+            Some(self.get_cell_mut_unchecked(pos))
+        }
+    }
+
+    fn get_cell_mut_unchecked(&mut self, pos: Self::Pos) -> &mut Self::Cell;
+}
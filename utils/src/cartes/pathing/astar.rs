@@ -2,7 +2,7 @@ use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::ops::Add;
 
-use crate::cartes::grid::Grid;
+use crate::cartes::grid::{Grid, GridMut};
 use crate::cartes::pos::Pos;
 use crate::num::cast::ToUsize;
 use crate::num::{One, Zero};
@@ -114,7 +114,7 @@ where
     let mut open = BinaryHeap::new();
     open.push(Reverse(CellRef::<G>::from_pos(
         start,
-        start.taxicab_dst(end),
+        grid.distance(start, end),
     )));
 
     let mut steps = 0usize;
@@ -125,7 +125,7 @@ where
         }
         *grid.get_cell_mut(opened.0.pos).unwrap() = PathState::Closed(
             opened.0.g_cost,
-            opened.0.pos.taxicab_dst(end),
+            grid.distance(opened.0.pos, end),
             opened.0.from,
         );
         if opened.0.pos == end {
@@ -144,7 +144,7 @@ where
                 open.push(Reverse(CellRef {
                     pos,
                     g_cost: opened.0.g_cost + One::one(),
-                    h_cost: pos.taxicab_dst(end),
+                    h_cost: grid.distance(pos, end),
                     from: opened.0.pos,
                 }))
             }
@@ -2,7 +2,7 @@ use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::ops::Add;
 
-use crate::cartes::grid::Grid;
+use crate::cartes::grid::{Grid, GridMut};
 use crate::cartes::pos::Pos;
 use crate::num::cast::ToUsize;
 use crate::num::{One, Zero};
@@ -174,7 +174,7 @@ pub fn dijkstra_in_place<G>(
     end: G::Pos,
 ) -> Option<Path<impl Iterator<Item = G::Pos> + use<G>, G::Pos>>
 where
-    G: Grid + Clone,
+    G: GridMut + Clone,
     G::Cell: DijkstraInPlace<G::Pos, <G::Pos as Pos>::N>,
     <G::Pos as Pos>::N: Zero + One + Copy + Ord + ToUsize + Add<Output = <G::Pos as Pos>::N>,
 {
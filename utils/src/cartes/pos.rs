@@ -8,4 +8,10 @@ pub trait Pos: Eq + Copy + Add + Sub + Neg {
 
     /// Taxicab distance
     fn taxicab_dst(self, other: Self) -> Self::N;
+
+    /// Smallest coordinate of both along each axis
+    fn min_axes(self, other: Self) -> Self;
+
+    /// Largest coordinate of both along each axis
+    fn max_axes(self, other: Self) -> Self;
 }
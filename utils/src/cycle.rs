@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+/// Shape of an eventually repeating sequence `x0, f(x0), f(f(x0)), ...`:
+/// the state after `prefix + period` steps is the state after `prefix` steps.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Cycle {
+    /// Number of steps before the first state which repeats
+    pub prefix: usize,
+    /// Number of steps between repeats
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as `step`, always below `prefix + period`
+    pub fn reduce(self, step: usize) -> usize {
+        if step < self.prefix {
+            step
+        } else {
+            self.prefix + (step - self.prefix) % self.period
+        }
+    }
+
+    /// Value of an accumulated metric after `target` steps,
+    /// e.g. the height of a tower which grows by the same amount every period.
+    ///
+    /// `totals[n]` is the metric after `n` steps and must cover at least `prefix + period` steps.
+    pub fn extrapolate<T>(self, totals: &[T], target: usize) -> T
+    where
+        T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + TryFrom<usize>,
+    {
+        if let Some(&total) = totals.get(target) {
+            return total;
+        }
+        let end = self.prefix + self.period;
+        assert!(
+            totals.len() > end,
+            "need totals up to step {end}, got {}",
+            totals.len()
+        );
+        let cycles = (target - self.prefix) / self.period;
+        let gain = totals[end] - totals[self.prefix];
+        let times = T::try_from(cycles)
+            .unwrap_or_else(|_| panic!("{cycles} cycles do not fit in the metric type"));
+        totals[self.reduce(target)] + gain * times
+    }
+}
+
+/// Floyd's tortoise and hare. Keeps only two states around but steps about
+/// three times as often as [brent].
+///
+/// Never returns if the sequence does not repeat.
+pub fn floyd<T, F>(start: T, f: F) -> Cycle
+where
+    T: Clone + PartialEq,
+    F: Fn(&T) -> T,
+{
+    let mut tortoise = f(&start);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&f(&hare));
+    }
+
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+
+    let mut period = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        period += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// Brent's algorithm. Keeps only two states around and finds the period directly.
+///
+/// Never returns if the sequence does not repeat.
+pub fn brent<T, F>(start: T, f: F) -> Cycle
+where
+    T: Clone + PartialEq,
+    F: Fn(&T) -> T,
+{
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = f(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = f(&hare);
+        period += 1;
+    }
+
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..period {
+        hare = f(&hare);
+    }
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, period }
+}
+
+/// Remembers the step at which every state was seen, for simulations which are easier
+/// to drive by hand than through a step function. States can be reduced to a key,
+/// e.g. the top rows of a tower instead of the whole tower.
+///
+/// Also records an accumulated metric per step to [extrapolate][Detector::extrapolate] from.
+#[derive(Debug, Clone)]
+pub struct Detector<K, T = ()> {
+    seen: HashMap<K, usize>,
+    totals: Vec<T>,
+    cycle: Option<Cycle>,
+}
+
+impl<K, T> Default for Detector<K, T> {
+    fn default() -> Self {
+        Self {
+            seen: HashMap::new(),
+            totals: vec![],
+            cycle: None,
+        }
+    }
+}
+
+impl<K: Hash + Eq, T> Detector<K, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `state` and the metric `total` as those after [steps][Self::steps] steps,
+    /// the first call being step `0`. Returns the cycle once any state repeats.
+    pub fn observe(&mut self, state: K, total: T) -> Option<Cycle> {
+        let step = self.totals.len();
+        self.totals.push(total);
+        if self.cycle.is_none() {
+            match self.seen.entry(state) {
+                Entry::Occupied(first) => {
+                    self.cycle = Some(Cycle {
+                        prefix: *first.get(),
+                        period: step - first.get(),
+                    })
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(step);
+                }
+            }
+        }
+        self.cycle
+    }
+
+    /// Number of states observed so far
+    pub fn steps(&self) -> usize {
+        self.totals.len()
+    }
+
+    /// The first cycle found, if any
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// Metric after `target` steps, [None] if it was not observed and no cycle was found yet.
+    /// See [Cycle::extrapolate].
+    pub fn extrapolate(&self, target: usize) -> Option<T>
+    where
+        T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + TryFrom<usize>,
+    {
+        match (self.totals.get(target), self.cycle) {
+            (Some(&total), _) => Some(total),
+            (None, Some(cycle)) => Some(cycle.extrapolate(&self.totals, target)),
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next(x: &u64) -> u64 {
+        (x * x + 1) % 1009
+    }
+
+    fn naive(start: u64) -> Cycle {
+        let mut detector = Detector::new();
+        let mut x = start;
+        loop {
+            if let Some(cycle) = detector.observe(x, ()) {
+                return cycle;
+            }
+            x = next(&x);
+        }
+    }
+
+    #[test]
+    fn algorithms() {
+        for start in [0, 3, 17, 500, 1008] {
+            let expected = naive(start);
+            assert_eq!(floyd(start, next), expected);
+            assert_eq!(brent(start, next), expected);
+        }
+        // A pure cycle, and a fixed point
+        assert_eq!(
+            brent(0, |x| (x + 1) % 5),
+            Cycle {
+                prefix: 0,
+                period: 5
+            }
+        );
+        assert_eq!(
+            floyd(9, |&x: &u64| x.min(4)),
+            Cycle {
+                prefix: 1,
+                period: 1
+            }
+        );
+    }
+
+    #[test]
+    fn reduce() {
+        let cycle = Cycle {
+            prefix: 3,
+            period: 4,
+        };
+        let reduced: Vec<_> = (0..12).map(|n| cycle.reduce(n)).collect();
+        assert_eq!(reduced, [0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, 3]);
+    }
+
+    #[test]
+    fn extrapolate() {
+        // Sum of the states visited, compared with simulating every step
+        let target = 1_000_000;
+        let mut brute = vec![0i64];
+        let mut x = 42;
+        for _ in 0..target {
+            x = next(&x);
+            brute.push(brute.last().unwrap() + x as i64);
+        }
+
+        let mut detector = Detector::new();
+        let (mut x, mut total) = (42, 0);
+        while detector.observe(x, total).is_none() {
+            x = next(&x);
+            total += x as i64;
+        }
+        assert!(detector.steps() < 100);
+        assert_eq!(detector.extrapolate(5), Some(brute[5]));
+        for n in [detector.steps(), 999, 123_457, target] {
+            assert_eq!(detector.extrapolate(n), Some(brute[n]), "step {n}");
+        }
+        assert_eq!(Detector::<u64, i64>::new().extrapolate(3), None);
+    }
+}
@@ -12,3 +12,15 @@ pub mod reading;
 
 /// Optimization utilities
 pub mod optim;
+
+/// Solution trait and runner
+pub mod runner;
+
+/// Timing and statistics for benchmarking solutions
+pub mod bench;
+
+/// Seedable pseudo-random numbers for generating test inputs
+pub mod rand;
+
+/// Cycle detection for extrapolating long simulations
+pub mod cycle;
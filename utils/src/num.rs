@@ -132,7 +132,7 @@ macro_rules! impl_remeuclid {
     }
 }
 
-impl_remeuclid!(rem_euclid, i8 i16 i32 i64 i128 u8 u16 u32 u64 u128);
+impl_remeuclid!(rem_euclid, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
 
 pub trait SignedType {
     type SignType;
@@ -0,0 +1,517 @@
+//! Small parser combinators over `&str` and `&[u8]`.
+//!
+//! A parser is any `Fn(I) -> PResult<I, T>`, returning the remaining input along with the
+//! parsed value. [complete] runs a parser over a whole input and turns a failure into a
+//! [ParseError].
+//!
+//! ```ignore
+//! let button = preceded(tag("Button A: X+"), separated_pair(unsigned, tag(", Y+"), unsigned));
+//! let (x, y): (u32, u32) = complete(line, button)?;
+//! ```
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::{ParseError, ParseResult, Source};
+
+/// Input a parser can run on
+pub trait Input: Copy {
+    fn bytes(&self) -> &[u8];
+
+    /// Splits after `n` bytes, which must lie on a char boundary for `&str`
+    fn split(self, n: usize) -> (Self, Self);
+
+    /// Largest `m <= n` at which [split][Input::split] may be called
+    fn boundary(&self, n: usize) -> usize {
+        n
+    }
+}
+
+impl Input for &str {
+    fn bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn split(self, n: usize) -> (Self, Self) {
+        self.split_at(n)
+    }
+
+    fn boundary(&self, mut n: usize) -> usize {
+        while !self.is_char_boundary(n) {
+            n -= 1;
+        }
+        n
+    }
+}
+
+impl Input for &[u8] {
+    fn bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn split(self, n: usize) -> (Self, Self) {
+        self.split_at(n)
+    }
+}
+
+/// What a parser was looking for when it failed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Expected {
+    Tag(&'static str),
+    Digit,
+    Newline,
+    End,
+    /// Free form description, e.g. `"a number that fits"`
+    Other(&'static str),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tag(tag) => write!(f, "expected {tag:?}"),
+            Self::Digit => write!(f, "expected a digit"),
+            Self::Newline => write!(f, "expected a line break"),
+            Self::End => write!(f, "expected the end of input"),
+            Self::Other(what) => write!(f, "expected {what}"),
+        }
+    }
+}
+
+/// A parser failing on the input `at`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Failure<I> {
+    /// Remaining input where the failure occurred
+    pub at: I,
+    pub expected: Expected,
+}
+
+impl<I: Input> Failure<I> {
+    pub fn new(at: I, expected: Expected) -> Self {
+        Self { at, expected }
+    }
+
+    /// The failure which got further into the input, preferring `self` on ties
+    pub fn furthest(self, other: Self) -> Self {
+        if other.at.bytes().len() < self.at.bytes().len() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Converts to a [ParseError] within `input`, of which `at` must be a suffix
+    pub fn into_error(self, input: I) -> ParseError {
+        let text = String::from_utf8_lossy(input.bytes());
+        let offset = input.bytes().len() - self.at.bytes().len();
+        ParseError::new(&text, offset, 1, self.expected.to_string())
+    }
+}
+
+pub type PResult<I, T> = Result<(I, T), Failure<I>>;
+
+/// Runs `parser` on all of `input`, which may only be followed by whitespace
+pub fn complete<I, T, P>(input: I, parser: P) -> ParseResult<T>
+where
+    I: Input,
+    P: Fn(I) -> PResult<I, T>,
+{
+    terminated(parser, preceded(whitespace, eof))(input)
+        .map(|(_, value)| value)
+        .map_err(|failure| failure.into_error(input))
+}
+
+impl<'a> Source<'a> {
+    /// Runs `parser` on all of `at`, see [complete]
+    pub fn parse_with<T, P>(&self, at: &'a str, parser: P) -> ParseResult<T>
+    where
+        P: Fn(&'a str) -> PResult<&'a str, T>,
+    {
+        terminated(parser, preceded(whitespace, eof))(at)
+            .map(|(_, value)| value)
+            .map_err(|failure| {
+                let offset = at.len() - failure.at.len();
+                let failed = &at[offset..];
+                self.error(&failed[..failed.len().min(1)], failure.expected.to_string())
+            })
+    }
+}
+
+/// Matches `tag` exactly
+pub fn tag<I: Input>(tag: &'static str) -> impl Fn(I) -> PResult<I, I> {
+    move |input: I| {
+        if input.bytes().starts_with(tag.as_bytes()) {
+            let (matched, rest) = input.split(tag.len());
+            Ok((rest, matched))
+        } else {
+            Err(Failure::new(input, Expected::Tag(tag)))
+        }
+    }
+}
+
+/// Longest prefix of bytes matching `pred`, possibly empty
+pub fn take_while<I, F>(pred: F) -> impl Fn(I) -> PResult<I, I>
+where
+    I: Input,
+    F: Fn(u8) -> bool,
+{
+    move |input: I| {
+        let n = input.bytes().iter().take_while(|&&b| pred(b)).count();
+        let (taken, rest) = input.split(input.boundary(n));
+        Ok((rest, taken))
+    }
+}
+
+/// Like [take_while] but requires at least one byte, failing with `expected` otherwise
+pub fn take_while1<I, F>(pred: F, expected: Expected) -> impl Fn(I) -> PResult<I, I>
+where
+    I: Input,
+    F: Fn(u8) -> bool,
+{
+    let take = take_while(pred);
+    move |input: I| match take(input)? {
+        (_, taken) if taken.bytes().is_empty() => Err(Failure::new(input, expected)),
+        ok => Ok(ok),
+    }
+}
+
+/// Spaces and tabs, possibly none
+pub fn spaces<I: Input>(input: I) -> PResult<I, I> {
+    take_while(|b| b == b' ' || b == b'\t')(input)
+}
+
+/// Any ASCII whitespace including line breaks, possibly none
+pub fn whitespace<I: Input>(input: I) -> PResult<I, I> {
+    take_while(|b: u8| b.is_ascii_whitespace())(input)
+}
+
+/// `\n` or `\r\n`
+pub fn newline<I: Input>(input: I) -> PResult<I, I> {
+    let bytes = input.bytes();
+    let n = if bytes.starts_with(b"\r\n") {
+        2
+    } else if bytes.starts_with(b"\n") {
+        1
+    } else {
+        return Err(Failure::new(input, Expected::Newline));
+    };
+    let (matched, rest) = input.split(n);
+    Ok((rest, matched))
+}
+
+/// Succeeds only at the end of the input
+pub fn eof<I: Input>(input: I) -> PResult<I, ()> {
+    if input.bytes().is_empty() {
+        Ok((input, ()))
+    } else {
+        Err(Failure::new(input, Expected::End))
+    }
+}
+
+/// Unsigned decimal integer
+pub fn unsigned<I, T>(input: I) -> PResult<I, T>
+where
+    I: Input,
+    T: FromStr,
+{
+    let (rest, digits) = take_while1(|b: u8| b.is_ascii_digit(), Expected::Digit)(input)?;
+    let value = std::str::from_utf8(digits.bytes())
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or(Failure::new(input, Expected::Other("a number that fits")))?;
+    Ok((rest, value))
+}
+
+/// Decimal integer with an optional `+` or `-` sign
+pub fn signed<I, T>(input: I) -> PResult<I, T>
+where
+    I: Input,
+    T: FromStr,
+{
+    let sign = input
+        .bytes()
+        .first()
+        .is_some_and(|&b| b == b'-' || b == b'+') as usize;
+    let (_, rest) = input.split(sign);
+    let (rest, _) = take_while1(|b: u8| b.is_ascii_digit(), Expected::Digit)(rest)?;
+    let len = input.bytes().len() - rest.bytes().len();
+    let (number, _) = input.split(len);
+    let value = std::str::from_utf8(number.bytes())
+        .ok()
+        .and_then(|s| s.strip_prefix('+').unwrap_or(s).parse().ok())
+        .ok_or(Failure::new(input, Expected::Other("a number that fits")))?;
+    Ok((rest, value))
+}
+
+pub fn map<I, A, B, P, F>(parser: P, f: F) -> impl Fn(I) -> PResult<I, B>
+where
+    P: Fn(I) -> PResult<I, A>,
+    F: Fn(A) -> B,
+{
+    move |input| parser(input).map(|(rest, a)| (rest, f(a)))
+}
+
+/// Replaces the output of `parser` with `value`
+pub fn value<I, A, T, P>(value: T, parser: P) -> impl Fn(I) -> PResult<I, T>
+where
+    P: Fn(I) -> PResult<I, A>,
+    T: Clone,
+{
+    move |input| parser(input).map(|(rest, _)| (rest, value.clone()))
+}
+
+/// Never fails, returning [None] if `parser` does
+pub fn opt<I, T, P>(parser: P) -> impl Fn(I) -> PResult<I, Option<T>>
+where
+    I: Copy,
+    P: Fn(I) -> PResult<I, T>,
+{
+    move |input| match parser(input) {
+        Ok((rest, value)) => Ok((rest, Some(value))),
+        Err(_) => Ok((input, None)),
+    }
+}
+
+pub fn pair<I, A, B, PA, PB>(a: PA, b: PB) -> impl Fn(I) -> PResult<I, (A, B)>
+where
+    PA: Fn(I) -> PResult<I, A>,
+    PB: Fn(I) -> PResult<I, B>,
+{
+    move |input| {
+        let (input, a) = a(input)?;
+        let (input, b) = b(input)?;
+        Ok((input, (a, b)))
+    }
+}
+
+/// `a`, then `sep` whose output is discarded, then `b`
+pub fn separated_pair<I, A, S, B, PA, PS, PB>(
+    a: PA,
+    sep: PS,
+    b: PB,
+) -> impl Fn(I) -> PResult<I, (A, B)>
+where
+    PA: Fn(I) -> PResult<I, A>,
+    PS: Fn(I) -> PResult<I, S>,
+    PB: Fn(I) -> PResult<I, B>,
+{
+    move |input| {
+        let (input, a) = a(input)?;
+        let (input, _) = sep(input)?;
+        let (input, b) = b(input)?;
+        Ok((input, (a, b)))
+    }
+}
+
+/// `parser` after `before`, whose output is discarded
+pub fn preceded<I, A, T, PA, P>(before: PA, parser: P) -> impl Fn(I) -> PResult<I, T>
+where
+    PA: Fn(I) -> PResult<I, A>,
+    P: Fn(I) -> PResult<I, T>,
+{
+    move |input| {
+        let (input, _) = before(input)?;
+        parser(input)
+    }
+}
+
+/// `parser` followed by `after`, whose output is discarded
+pub fn terminated<I, T, B, P, PB>(parser: P, after: PB) -> impl Fn(I) -> PResult<I, T>
+where
+    P: Fn(I) -> PResult<I, T>,
+    PB: Fn(I) -> PResult<I, B>,
+{
+    move |input| {
+        let (input, value) = parser(input)?;
+        let (input, _) = after(input)?;
+        Ok((input, value))
+    }
+}
+
+/// `parser` between `open` and `close`, e.g. brackets
+pub fn delimited<I, A, T, B, PA, P, PB>(
+    open: PA,
+    parser: P,
+    close: PB,
+) -> impl Fn(I) -> PResult<I, T>
+where
+    PA: Fn(I) -> PResult<I, A>,
+    P: Fn(I) -> PResult<I, T>,
+    PB: Fn(I) -> PResult<I, B>,
+{
+    move |input| {
+        let (input, _) = open(input)?;
+        let (input, value) = parser(input)?;
+        let (input, _) = close(input)?;
+        Ok((input, value))
+    }
+}
+
+/// `parser` surrounded by optional [spaces]
+pub fn ws<I, T, P>(parser: P) -> impl Fn(I) -> PResult<I, T>
+where
+    I: Input,
+    P: Fn(I) -> PResult<I, T>,
+{
+    delimited(spaces, parser, spaces)
+}
+
+/// Zero or more repetitions of `parser`
+pub fn many<I, T, P>(parser: P) -> impl Fn(I) -> PResult<I, Vec<T>>
+where
+    I: Input,
+    P: Fn(I) -> PResult<I, T>,
+{
+    move |mut input: I| {
+        let mut values = vec![];
+        while let Ok((rest, value)) = parser(input) {
+            // Stop on parsers which succeed without consuming anything
+            if rest.bytes().len() == input.bytes().len() {
+                break;
+            }
+            values.push(value);
+            input = rest;
+        }
+        Ok((input, values))
+    }
+}
+
+/// One or more `item`s separated by `sep`. A trailing separator is left unconsumed.
+pub fn separated<I, T, S, P, PS>(item: P, sep: PS) -> impl Fn(I) -> PResult<I, Vec<T>>
+where
+    I: Input,
+    P: Fn(I) -> PResult<I, T>,
+    PS: Fn(I) -> PResult<I, S>,
+{
+    move |input: I| {
+        let (mut input, first) = item(input)?;
+        let mut values = vec![first];
+        loop {
+            let Ok((after_sep, _)) = sep(input) else {
+                break;
+            };
+            let Ok((rest, value)) = item(after_sep) else {
+                break;
+            };
+            values.push(value);
+            input = rest;
+        }
+        Ok((input, values))
+    }
+}
+
+/// Choice between parsers, implemented for tuples of parsers with the same output
+pub trait Alt<I, T> {
+    fn choose(&self, input: I) -> PResult<I, T>;
+}
+
+macro_rules! impl_alt {
+    ($first:ident $first_idx:tt $(, $p:ident $idx:tt)*) => {
+        impl<I, T, $first $(, $p)*> Alt<I, T> for ($first, $($p,)*)
+        where
+            I: Input,
+            $first: Fn(I) -> PResult<I, T>,
+            $($p: Fn(I) -> PResult<I, T>,)*
+        {
+            fn choose(&self, input: I) -> PResult<I, T> {
+                #[allow(unused_mut)]
+                let mut failure = match (self.$first_idx)(input) {
+                    Ok(ok) => return Ok(ok),
+                    Err(failure) => failure,
+                };
+                $(
+                    match (self.$idx)(input) {
+                        Ok(ok) => return Ok(ok),
+                        Err(other) => failure = failure.furthest(other),
+                    }
+                )*
+                Err(failure)
+            }
+        }
+    };
+}
+
+impl_alt!(A 0);
+impl_alt!(A 0, B 1);
+impl_alt!(A 0, B 1, C 2);
+impl_alt!(A 0, B 1, C 2, D 3);
+impl_alt!(A 0, B 1, C 2, D 3, E 4);
+impl_alt!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_alt!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_alt!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// The first of `parsers` to succeed. On failure reports whichever got furthest.
+pub fn alt<I, T, A: Alt<I, T>>(parsers: A) -> impl Fn(I) -> PResult<I, T> {
+    move |input| parsers.choose(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(unsigned::<_, u32>("123abc"), Ok(("abc", 123)));
+        assert_eq!(signed::<_, i64>("-45,"), Ok((",", -45)));
+        assert_eq!(signed::<_, i8>(b"+7".as_slice()), Ok((b"".as_slice(), 7)));
+        assert_eq!(
+            unsigned::<_, u8>("300").unwrap_err().expected,
+            Expected::Other("a number that fits")
+        );
+        assert_eq!(
+            signed::<_, i32>("-x").unwrap_err(),
+            Failure::new("x", Expected::Digit)
+        );
+    }
+
+    #[test]
+    fn combinators() {
+        let list = delimited(
+            tag("["),
+            separated(ws(unsigned::<_, u32>), tag(",")),
+            tag("]"),
+        );
+        assert_eq!(list("[1, 2 ,3]!"), Ok(("!", vec![1, 2, 3])));
+        assert_eq!(
+            complete("[1, 2,]", &list).unwrap_err().column,
+            6,
+            "trailing separator"
+        );
+
+        let dir = alt((value(1, tag("up")), value(-1, tag("down"))));
+        assert_eq!(
+            many(terminated(&dir, opt(tag(" "))))("up down up"),
+            Ok(("", vec![1, -1, 1]))
+        );
+        assert_eq!(
+            dir("dow").unwrap_err(),
+            Failure::new("dow", Expected::Tag("up"))
+        );
+
+        let lines = separated(
+            separated_pair(unsigned::<_, u8>, tag("-"), unsigned),
+            newline,
+        );
+        assert_eq!(complete("1-2\r\n3-4\n", lines), Ok(vec![(1, 2), (3, 4)]));
+    }
+
+    #[test]
+    fn errors() {
+        let button = preceded(
+            tag("Button A: X+"),
+            separated_pair(unsigned::<_, u32>, tag(", Y+"), unsigned::<_, u32>),
+        );
+        let input = "Button A: X+94, Y+34\nButton A: X+22, Z+67";
+        let src = Source::new(input);
+        let lines: Vec<_> = src.lines().collect();
+        assert_eq!(src.parse_with(lines[0], &button), Ok((94, 34)));
+        let err = src.parse_with(lines[1], &button).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 15));
+        assert_eq!(err.message, "expected \", Y+\"");
+
+        let err = complete(b"1 2 x".as_slice(), separated(unsigned::<_, u8>, spaces)).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.message.as_str()),
+            (1, 5, "expected the end of input")
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Failure to parse puzzle input, pointing at where in the input it happened
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in chars
+    pub column: usize,
+    /// Number of chars the error spans, at least 1
+    pub len: usize,
+    /// The offending line
+    pub snippet: String,
+    pub message: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    /// Error spanning `len` bytes at byte `offset` into `input`
+    pub fn new(input: &str, offset: usize, len: usize, message: impl Into<String>) -> Self {
+        let offset = floor_char_boundary(input, offset.min(input.len()));
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let snippet = input[line_start..line_end].trim_end_matches('\r');
+        let end = floor_char_boundary(input, (offset + len).min(line_end));
+        Self {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            len: input[offset..end].chars().count().max(1),
+            snippet: snippet.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error spanning `at`, which should be a subslice of `input`.
+    /// Slices from elsewhere are reported at the start of `input`.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = subslice_offset(input, at).unwrap_or(0);
+        Self::new(input, offset, at.len(), message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let number = self.line.to_string();
+        let pad = " ".repeat(number.len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{pad} |")?;
+        writeln!(f, "{number} | {}", self.snippet)?;
+        write!(
+            f,
+            "{pad} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len)
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of `part` within `whole`, if `part` is a subslice of it
+fn subslice_offset(whole: &str, part: &str) -> Option<usize> {
+    let start = whole.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).checked_sub(start)?;
+    (offset + part.len() <= whole.len()).then_some(offset)
+}
+
+fn floor_char_boundary(s: &str, mut i: usize) -> usize {
+    while !s.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+/// `n` followed by `noun`, pluralised, e.g. "1 number" and "2 numbers" in error messages
+pub(crate) fn count(n: usize, noun: &str) -> String {
+    if n == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{n} {noun}s")
+    }
+}
+
+/// Puzzle input whose subslices can be parsed with errors pointing back into it.
+///
+/// Every `at` argument should be a subslice of the source, as obtained by splitting it
+/// (including through the methods here).
+///
+/// ```ignore
+/// let src = Source::new(input);
+/// for line in src.lines() {
+///     let (name, value) = src.split_once(line, ": ")?;
+///     let value: u32 = src.parse(value)?;
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Source<'a>(&'a str);
+
+impl<'a> Source<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self(input)
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Error spanning `at`
+    pub fn error(&self, at: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.0, at, message)
+    }
+
+    /// Lines without their line endings
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.0.lines()
+    }
+
+    /// Parses `at` after trimming surrounding whitespace
+    pub fn parse<T>(&self, at: &'a str) -> ParseResult<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let at = at.trim();
+        at.parse().map_err(|e| {
+            let ty = std::any::type_name::<T>();
+            let ty = ty.rsplit("::").next().unwrap_or(ty);
+            self.error(at, format!("invalid {ty}: {e}"))
+        })
+    }
+
+    pub fn split_once(&self, at: &'a str, sep: &str) -> ParseResult<(&'a str, &'a str)> {
+        at.split_once(sep)
+            .ok_or_else(|| self.error(at, format!("expected {sep:?}")))
+    }
+
+    pub fn strip_prefix(&self, at: &'a str, prefix: &str) -> ParseResult<&'a str> {
+        at.strip_prefix(prefix)
+            .ok_or_else(|| self.error(at, format!("expected {prefix:?}")))
+    }
+
+    /// Unwraps `value`, otherwise reporting `message` at `at`
+    pub fn expect<T>(&self, value: Option<T>, at: &str, message: &str) -> ParseResult<T> {
+        value.ok_or_else(|| self.error(at, message))
+    }
+
+    /// Error at the end of the input, for input which ends too early
+    pub fn eof(&self, message: impl Into<String>) -> ParseError {
+        let end = self.0.trim_end();
+        ParseError::new(self.0, end.len(), 1, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let input = "a: 1\r\nb: x2\nc: 3";
+        let src = Source::new(input);
+        let line = src.lines().nth(1).unwrap();
+        let (_, value) = src.split_once(line, ":").unwrap();
+        let err = src.parse::<u32>(value).unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 4, 2));
+        assert_eq!(err.snippet, "b: x2");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 4: invalid u32: invalid digit found in string\n  |\n2 | b: x2\n  |    ^^"
+        );
+    }
+
+    #[test]
+    fn helpers() {
+        let src = Source::new("Register A: 7\n");
+        let line = src.lines().next().unwrap();
+        assert_eq!(src.strip_prefix(line, "Register A: "), Ok("7"));
+        let err = src.strip_prefix(line, "Program: ").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (1, 1, 13));
+        let err = src.eof("expected a program");
+        assert_eq!((err.line, err.column), (1, 14));
+        assert_eq!(ParseError::at("abc", "elsewhere", "x").column, 1);
+        assert_eq!(ParseError::new("é", 1, 1, "x").column, 1);
+    }
+}
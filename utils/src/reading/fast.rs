@@ -0,0 +1,222 @@
+//! Integer scanning straight from bytes, for inputs with very many numbers.
+//!
+//! Digit runs are parsed 8 bytes at a time (SWAR) without UTF-8 validation or allocation.
+//! Numbers are accumulated in a [u64], so digit runs which do not fit wrap around.
+//! For friendlier errors on small inputs see [numbers][super::numbers].
+
+use std::marker::PhantomData;
+
+const ZEROS: u64 = u64::from_ne_bytes([b'0'; 8]);
+const HIGH_BITS: u64 = u64::from_ne_bytes([0x80; 8]);
+/// Added to a byte in `0..=9` keeps its high bit clear, while `10..` sets it
+const OVER_NINE: u64 = u64::from_ne_bytes([0x76; 8]);
+
+/// Number of leading ASCII digits in the little endian `chunk`
+#[inline]
+fn digit_count(chunk: u64) -> usize {
+    let sub = chunk.wrapping_sub(ZEROS);
+    // Borrows and carries only move towards later bytes, so the first non-digit is exact
+    let non_digits = (sub | sub.wrapping_add(OVER_NINE)) & HIGH_BITS;
+    (non_digits.trailing_zeros() / 8) as usize
+}
+
+/// Value of the first `len` digits of the little endian `chunk`, `1..=8` of them
+#[inline]
+fn chunk_value(chunk: u64, len: usize) -> u64 {
+    let mut v = chunk.wrapping_sub(ZEROS) << (8 * (8 - len));
+    v = (v * 10 + (v >> 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v * 100 + (v >> 16)) & 0x0000_FFFF_0000_FFFF;
+    (v * 10000 + (v >> 32)) & 0xFFFF_FFFF
+}
+
+const POW10: [u64; 9] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+];
+
+/// Parses the digits at the start of `bytes` one at a time, returning the value and the
+/// number of digits
+#[inline]
+pub fn leading_u64_scalar(bytes: &[u8]) -> (u64, usize) {
+    let mut value = 0u64;
+    let mut len = 0;
+    for &b in bytes {
+        let digit = b.wrapping_sub(b'0');
+        if digit >= 10 {
+            break;
+        }
+        value = value.wrapping_mul(10).wrapping_add(digit as u64);
+        len += 1;
+    }
+    (value, len)
+}
+
+/// Parses the digits at the start of `bytes`, returning the value and the number of digits
+#[inline]
+pub fn leading_u64(bytes: &[u8]) -> (u64, usize) {
+    let mut value = 0u64;
+    let mut len = 0;
+    while let Some(chunk) = bytes.get(len..len + 8) {
+        let chunk = u64::from_le_bytes(chunk.try_into().unwrap());
+        let digits = digit_count(chunk);
+        if digits == 0 {
+            return (value, len);
+        }
+        value = value
+            .wrapping_mul(POW10[digits])
+            .wrapping_add(chunk_value(chunk, digits));
+        len += digits;
+        if digits < 8 {
+            return (value, len);
+        }
+    }
+    let (rest, digits) = leading_u64_scalar(&bytes[len..]);
+    (
+        value
+            .wrapping_mul(10u64.wrapping_pow(digits as u32))
+            .wrapping_add(rest),
+        len + digits,
+    )
+}
+
+/// Every run of ASCII digits in `bytes`, see [uints]
+#[derive(Debug, Clone)]
+pub struct Uints<'a, T> {
+    bytes: &'a [u8],
+    _marker: PhantomData<T>,
+}
+
+impl<T: TryFrom<u64>> Iterator for Uints<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.bytes.iter().position(u8::is_ascii_digit)?;
+        let (value, len) = leading_u64(&self.bytes[start..]);
+        self.bytes = &self.bytes[start + len..];
+        Some(cast(value))
+    }
+}
+
+/// Every integer in `bytes` including a `-` sign, see [ints]
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    bytes: &'a [u8],
+    /// Whether the byte before `bytes` is a digit or letter, which makes a `-` a separator
+    after_word: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: TryFrom<i64>> Iterator for Ints<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.bytes.iter().position(u8::is_ascii_digit)?;
+        let negative = match start {
+            0 => false,
+            1 => self.bytes[0] == b'-' && !self.after_word,
+            _ => self.bytes[start - 1] == b'-' && !self.bytes[start - 2].is_ascii_alphanumeric(),
+        };
+        let (value, len) = leading_u64(&self.bytes[start..]);
+        self.bytes = &self.bytes[start + len..];
+        self.after_word = true;
+        let value = value as i64;
+        Some(cast(if negative {
+            value.wrapping_neg()
+        } else {
+            value
+        }))
+    }
+}
+
+#[inline]
+fn cast<T: TryFrom<U>, U: Copy + std::fmt::Display>(value: U) -> T {
+    T::try_from(value)
+        .unwrap_or_else(|_| panic!("{value} does not fit in {}", std::any::type_name::<T>()))
+}
+
+/// Every run of ASCII digits in `bytes`, ignoring signs.
+///
+/// Panics if a number does not fit in `T`.
+pub fn uints<T: TryFrom<u64>>(bytes: &[u8]) -> Uints<'_, T> {
+    Uints {
+        bytes,
+        _marker: PhantomData,
+    }
+}
+
+/// Every integer in `bytes`, including negative ones. Like
+/// [numbers::ints][super::numbers::ints] a `-` directly after a digit or letter is not a sign.
+///
+/// Panics if a number does not fit in `T`.
+pub fn ints<T: TryFrom<i64>>(bytes: &[u8]) -> Ints<'_, T> {
+    Ints {
+        bytes,
+        after_word: false,
+        _marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rand::Rng;
+
+    #[test]
+    fn leading() {
+        assert_eq!(
+            leading_u64(b"12345678901234567890 "),
+            (12345678901234567890, 20)
+        );
+        assert_eq!(leading_u64(b"0042,"), (42, 4));
+        assert_eq!(leading_u64(b"x1"), (0, 0));
+        assert_eq!(leading_u64(b"9/:0"), (9, 1));
+        assert_eq!(leading_u64(b""), (0, 0));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = Rng::new(15);
+        for _ in 0..2000 {
+            let len = rng.range(1..21) as usize;
+            let mut bytes: Vec<u8> = (0..len).map(|_| b'0' + rng.range(0..10) as u8).collect();
+            bytes.push(*rng.choose(b" \n,-:/"));
+            bytes.extend(b"12345678");
+            let text = std::str::from_utf8(&bytes[..len]).unwrap();
+            let expected = text
+                .parse::<u64>()
+                .unwrap_or_else(|_| leading_u64_scalar(&bytes).0);
+            assert_eq!(leading_u64(&bytes), (expected, len), "{text}");
+            assert_eq!(leading_u64_scalar(&bytes), (expected, len), "{text}");
+        }
+    }
+
+    #[test]
+    fn iterate() {
+        let input = b"3   4\r\n-4   3\np=-10,7 v=1-2";
+        assert_eq!(
+            uints::<u32>(input).collect::<Vec<_>>(),
+            [3, 4, 4, 3, 10, 7, 1, 2]
+        );
+        assert_eq!(
+            ints::<i16>(input).collect::<Vec<_>>(),
+            [3, 4, -4, 3, -10, 7, 1, 2]
+        );
+        assert_eq!(ints::<i8>(b"-1").collect::<Vec<_>>(), [-1]);
+        assert_eq!(uints::<u8>(b"").count(), 0);
+    }
+
+    #[test]
+    #[should_panic = "256 does not fit in u8"]
+    fn overflow() {
+        uints::<u8>(b"256").for_each(drop);
+    }
+}
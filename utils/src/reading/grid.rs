@@ -0,0 +1,218 @@
+use std::fmt::Display;
+
+use crate::cartes::dim2::grid::{Grid2, Pos};
+
+/// Why an input could not be read as a grid
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GridError {
+    /// The input has no rows
+    Empty,
+    /// A row is not as wide as the first one
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The mapper rejected a byte
+    InvalidByte { pos: Pos, byte: u8 },
+    /// A marker does not occur in the input
+    MissingMarker(u8),
+    /// A marker occurs more than once
+    DuplicateMarker { marker: u8, first: Pos, second: Pos },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty grid"),
+            Self::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} is {found} wide, expected {expected} like the first row",
+                row + 1
+            ),
+            Self::InvalidByte { pos, byte } => write!(
+                f,
+                "invalid cell {:?} at row {}, column {}",
+                *byte as char,
+                pos.1 + 1,
+                pos.0 + 1
+            ),
+            Self::MissingMarker(marker) => write!(f, "no {:?} in grid", *marker as char),
+            Self::DuplicateMarker {
+                marker,
+                first,
+                second,
+            } => write!(
+                f,
+                "{:?} found at both row {}, column {} and row {}, column {}",
+                *marker as char,
+                first.1 + 1,
+                first.0 + 1,
+                second.1 + 1,
+                second.0 + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// Reads every cell of a rectangular grid, calling `f` with its position and byte.
+///
+/// Rows end in `\n` or `\r\n`, a trailing line break is optional.
+fn read<T, F>(input: &[u8], mut f: F) -> Result<Grid2<T>, GridError>
+where
+    F: FnMut(Pos, u8) -> Result<T, GridError>,
+{
+    let input = input.strip_suffix(b"\n").unwrap_or(input);
+    let input = input.strip_suffix(b"\r").unwrap_or(input);
+    if input.is_empty() {
+        return Err(GridError::Empty);
+    }
+
+    let mut data = Vec::with_capacity(input.len());
+    let mut cols = 0;
+    let mut rows = 0;
+    for (y, line) in input.split(|&b| b == b'\n').enumerate() {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if y == 0 {
+            cols = line.len();
+        } else if line.len() != cols {
+            return Err(GridError::Ragged {
+                row: y,
+                expected: cols,
+                found: line.len(),
+            });
+        }
+        for (x, &byte) in line.iter().enumerate() {
+            data.push(f(Pos::from((x as isize, y as isize)), byte)?);
+        }
+        rows += 1;
+    }
+    Ok(Grid2::from_raw(data, cols, rows))
+}
+
+/// Parses a grid, mapping each byte to a cell with `f`
+pub fn byte_with<F, T>(input: impl AsRef<[u8]>, mut f: F) -> Result<Grid2<T>, GridError>
+where
+    F: FnMut(u8) -> T,
+{
+    read(input.as_ref(), |_, byte| Ok(f(byte)))
+}
+
+/// Parses a grid, mapping each byte to a cell with `f`.
+/// Bytes for which `f` returns [None] are reported as [GridError::InvalidByte].
+pub fn try_byte_with<F, T>(input: impl AsRef<[u8]>, mut f: F) -> Result<Grid2<T>, GridError>
+where
+    F: FnMut(u8) -> Option<T>,
+{
+    read(input.as_ref(), |pos, byte| {
+        f(byte).ok_or(GridError::InvalidByte { pos, byte })
+    })
+}
+
+/// Like [try_byte_with], also returning the position of each of `markers` (e.g. `S` and `E`),
+/// which must each occur exactly once. Marker bytes are passed to `f` like any other byte.
+///
+/// ```ignore
+/// let (grid, [start, end]) = byte_with_markers(input, *b"SE", Cell::from_byte)?;
+/// ```
+pub fn byte_with_markers<F, T, const N: usize>(
+    input: impl AsRef<[u8]>,
+    markers: [u8; N],
+    mut f: F,
+) -> Result<(Grid2<T>, [Pos; N]), GridError>
+where
+    F: FnMut(u8) -> Option<T>,
+{
+    let mut found: [Option<Pos>; N] = [None; N];
+    let grid = read(input.as_ref(), |pos, byte| {
+        if let Some(i) = markers.iter().position(|&m| m == byte) {
+            if let Some(first) = found[i] {
+                return Err(GridError::DuplicateMarker {
+                    marker: byte,
+                    first,
+                    second: pos,
+                });
+            }
+            found[i] = Some(pos);
+        }
+        f(byte).ok_or(GridError::InvalidByte { pos, byte })
+    })?;
+
+    let mut positions = [Pos::zero(); N];
+    for (i, pos) in found.into_iter().enumerate() {
+        positions[i] = pos.ok_or(GridError::MissingMarker(markers[i]))?;
+    }
+    Ok((grid, positions))
+}
+
+pub fn byte_and<F>(input: &[u8], f: F)
+where
+    F: Fn(usize, u8),
+{
+    input.iter().enumerate().for_each(|(i, c)| f(i, *c));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cartes::dim2::vec::Vec2;
+    use crate::cartes::grid::Grid;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let grid = byte_with("#.\r\n.#\r\n", |b| b == b'#').unwrap();
+        assert_eq!((grid.cols, grid.rows), (2, 2));
+        assert_eq!(grid.as_slice(), &[true, false, false, true]);
+
+        let grid = byte_with(b"123\n456".as_slice(), |b| b - b'0').unwrap();
+        assert_eq!(grid.get_cell(Vec2(2, 1)), Some(&6));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(byte_with("\n", |b| b).unwrap_err(), GridError::Empty);
+        assert_eq!(
+            byte_with("..\n...\n", |b| b).unwrap_err(),
+            GridError::Ragged {
+                row: 1,
+                expected: 2,
+                found: 3
+            }
+        );
+        let err = try_byte_with("..\n.x", |b| (b == b'.').then_some(())).unwrap_err();
+        assert_eq!(
+            err,
+            GridError::InvalidByte {
+                pos: Vec2(1, 1),
+                byte: b'x'
+            }
+        );
+        assert_eq!(err.to_string(), "invalid cell 'x' at row 2, column 2");
+    }
+
+    #[test]
+    fn markers() {
+        let wall = |b| Some(b == b'#');
+        let (grid, [start, end]) = byte_with_markers("S.#\n#.E", *b"SE", wall).unwrap();
+        assert_eq!((start, end), (Vec2(0, 0), Vec2(2, 1)));
+        assert!(!grid[end]);
+
+        assert_eq!(
+            byte_with_markers("S..", *b"SE", wall).unwrap_err(),
+            GridError::MissingMarker(b'E')
+        );
+        let err = byte_with_markers("S.S", *b"S", wall).unwrap_err();
+        assert!(matches!(err, GridError::DuplicateMarker { .. }));
+        assert_eq!(
+            err.to_string(),
+            "'S' found at both row 1, column 1 and row 1, column 3"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Overrides the directory holding `<year>/<day>.txt` inputs
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Path to a single input file, used in place of the input directory. `-` reads from stdin.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Name of the input directory searched for from the current directory upwards
+pub const INPUT_DIR: &str = "inputs";
+
+/// Where to read a puzzle input from
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub enum InputSource {
+    /// `inputs/<year>/<day>.txt`, see [input_dir]
+    #[default]
+    Cache,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses a user supplied source where `-` stands for stdin
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::Path(PathBuf::from(arg))
+        }
+    }
+
+    /// Uses `explicit` if given, falling back to [INPUT_ENV] and then to the input cache
+    pub fn resolve(explicit: Option<&str>) -> Self {
+        match explicit {
+            Some(arg) => Self::from_arg(arg),
+            None => match std::env::var(INPUT_ENV) {
+                Ok(arg) if !arg.is_empty() => Self::from_arg(&arg),
+                _ => Self::Cache,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// No input is cached for this puzzle
+    Missing {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        err: std::io::Error,
+    },
+    Stdin(std::io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { year, day, path } => write!(
+                f,
+                "no input for {year} day {day} at {}; save it there, set {INPUT_ENV} or pass an input path",
+                path.display()
+            ),
+            Self::Io { path, err } => write!(f, "could not read {}: {err}", path.display()),
+            Self::Stdin(err) => write!(f, "could not read stdin: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Directory holding cached inputs.
+///
+/// Uses [INPUT_DIR_ENV] when set, otherwise the closest [INPUT_DIR] directory found walking up
+/// from the current directory. Defaults to `./inputs` if none exists yet.
+pub fn input_dir() -> PathBuf {
+    if let Ok(dir) = std::env::var(INPUT_DIR_ENV)
+        && !dir.is_empty()
+    {
+        return PathBuf::from(dir);
+    }
+    let cwd = std::env::current_dir().unwrap_or_default();
+    cwd.ancestors()
+        .map(|p| p.join(INPUT_DIR))
+        .find(|p| p.is_dir())
+        .unwrap_or_else(|| cwd.join(INPUT_DIR))
+}
+
+/// `<dir>/<year>/<day>.txt`
+pub fn cache_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("{day}.txt"))
+}
+
+/// Reads the input of a puzzle from `source`
+pub fn load(year: u16, day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Cache => {
+            let path = cache_path(&input_dir(), year, day);
+            if !path.is_file() {
+                return Err(InputError::Missing { year, day, path });
+            }
+            read_file(path)
+        }
+        InputSource::Path(path) => read_file(path.clone()),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+            Ok(input)
+        }
+    }
+}
+
+fn read_file(path: PathBuf) -> Result<String, InputError> {
+    std::fs::read_to_string(&path).map_err(|err| InputError::Io { path, err })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            cache_path(Path::new("inputs"), 2024, 16),
+            PathBuf::from("inputs/2024/16.txt")
+        );
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::resolve(Some("a.txt")),
+            InputSource::Path(PathBuf::from("a.txt"))
+        );
+    }
+
+    #[test]
+    fn missing() {
+        let path = PathBuf::from("/nonexistent/aoc/input.txt");
+        let err = load(2024, 16, &InputSource::Path(path)).unwrap_err();
+        assert!(matches!(err, InputError::Io { .. }));
+    }
+}
@@ -0,0 +1,208 @@
+//! Pulling integers out of text which is mostly noise, e.g. `p=0,4 v=3,-3`.
+//!
+//! Signed variants treat a `-` as a sign only when it is not preceded by a digit or letter,
+//! so ranges like `3-5` yield `3` and `5`.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::error::count;
+use super::{ParseResult, Source};
+
+/// Substrings of `input` which look like integers
+#[derive(Debug, Clone)]
+pub struct Spans<'a> {
+    input: &'a str,
+    pos: usize,
+    signed: bool,
+}
+
+impl<'a> Spans<'a> {
+    pub fn new(input: &'a str, signed: bool) -> Self {
+        Self {
+            input,
+            pos: 0,
+            signed,
+        }
+    }
+}
+
+impl<'a> Iterator for Spans<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.input.as_bytes();
+        let digit = bytes[self.pos..].iter().position(u8::is_ascii_digit)? + self.pos;
+        let mut start = digit;
+        if self.signed
+            && digit > 0
+            && bytes[digit - 1] == b'-'
+            && (digit < 2 || !bytes[digit - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        let end = bytes[digit..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |n| digit + n);
+        self.pos = end;
+        Some(&self.input[start..end])
+    }
+}
+
+/// Every integer in `input`, including negative ones.
+///
+/// Numbers which do not fit in `T` give an error pointing at them.
+pub fn ints<T>(input: &str) -> impl Iterator<Item = ParseResult<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let src = Source::new(input);
+    Spans::new(input, true).map(move |span| src.parse(span))
+}
+
+/// Every run of digits in `input`, ignoring signs.
+///
+/// Numbers which do not fit in `T` give an error pointing at them.
+pub fn uints<T>(input: &str) -> impl Iterator<Item = ParseResult<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let src = Source::new(input);
+    Spans::new(input, false).map(move |span| src.parse(span))
+}
+
+fn exactly<const N: usize>(input: &str, signed: bool) -> ParseResult<[&str; N]> {
+    let src = Source::new(input);
+    let mut spans = Spans::new(input, signed);
+    let mut found = [""; N];
+    for (i, slot) in found.iter_mut().enumerate() {
+        *slot = spans.next().ok_or_else(|| {
+            src.error(
+                input.trim(),
+                format!("expected {}, found {i}", count(N, "number")),
+            )
+        })?;
+    }
+    if let Some(extra) = spans.next() {
+        let total = N + 1 + spans.count();
+        return Err(src.error(
+            extra,
+            format!("expected {}, found {total}", count(N, "number")),
+        ));
+    }
+    Ok(found)
+}
+
+fn parse_exactly<T, const N: usize>(input: &str, signed: bool) -> ParseResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let src = Source::new(input);
+    let spans = exactly::<N>(input, signed)?;
+    let mut values = Vec::with_capacity(N);
+    for span in spans {
+        values.push(src.parse(span)?);
+    }
+    Ok(values.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+/// Exactly `N` integers of `input`, see [ints]
+///
+/// ```ignore
+/// let [px, py, vx, vy] = ints_n::<i32, 4>("p=0,4 v=3,-3")?;
+/// ```
+pub fn ints_n<T, const N: usize>(input: &str) -> ParseResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_exactly(input, true)
+}
+
+/// Exactly `N` unsigned integers of `input`, see [uints]
+pub fn uints_n<T, const N: usize>(input: &str) -> ParseResult<[T; N]>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_exactly(input, false)
+}
+
+/// Tuples which can be parsed from a fixed number of integers, see [ints_tuple]
+pub trait FromInts: Sized {
+    fn from_ints(input: &str) -> ParseResult<Self>;
+}
+
+macro_rules! impl_from_ints {
+    ($n:literal; $($t:ident $idx:tt),+) => {
+        impl<$($t),+> FromInts for ($($t,)+)
+        where
+            $($t: FromStr, $t::Err: Display,)+
+        {
+            fn from_ints(input: &str) -> ParseResult<Self> {
+                let src = Source::new(input);
+                let spans = exactly::<$n>(input, true)?;
+                Ok(($(src.parse::<$t>(spans[$idx])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_ints!(1; A 0);
+impl_from_ints!(2; A 0, B 1);
+impl_from_ints!(3; A 0, B 1, C 2);
+impl_from_ints!(4; A 0, B 1, C 2, D 3);
+impl_from_ints!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_ints!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Exactly as many integers as the tuple `T` has fields, each of its own type
+///
+/// ```ignore
+/// let (id, x, y): (usize, i64, i64) = ints_tuple("#3 @ -2,7")?;
+/// ```
+pub fn ints_tuple<T: FromInts>(input: &str) -> ParseResult<T> {
+    T::from_ints(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterate() {
+        let line = "p=0,4 v=3,-3 x-7 10-12";
+        assert_eq!(
+            ints::<i32>(line).collect::<ParseResult<Vec<_>>>(),
+            Ok(vec![0, 4, 3, -3, 7, 10, 12])
+        );
+        assert_eq!(
+            uints::<u8>("-1, -2").collect::<ParseResult<Vec<_>>>(),
+            Ok(vec![1, 2])
+        );
+        assert_eq!(ints::<u64>("no numbers").count(), 0);
+
+        let err = uints::<u8>("1\n2 300")
+            .collect::<ParseResult<Vec<_>>>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 3, 3));
+        assert!(ints::<u32>("-4").next().unwrap().is_err());
+    }
+
+    #[test]
+    fn fixed() {
+        assert_eq!(ints_n::<i64, 2>("Button A: X+94, Y+34"), Ok([94, 34]));
+        assert_eq!(ints_tuple::<(u8, i16)>("7: -300"), Ok((7, -300)));
+
+        let err = ints_n::<u32, 3>("1 2").unwrap_err();
+        assert_eq!(err.message, "expected 3 numbers, found 2");
+        let err = uints_n::<u32, 1>("1 22 3").unwrap_err();
+        assert_eq!((err.column, err.len), (3, 2));
+        assert_eq!(err.message, "expected 1 number, found 3");
+        let err = ints_tuple::<(u8, u8)>("1 256").unwrap_err();
+        assert_eq!(err.column, 3);
+    }
+}
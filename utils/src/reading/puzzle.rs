@@ -0,0 +1,230 @@
+use std::path::{Path, PathBuf};
+
+/// One `<article>` of a puzzle description, i.e. the text of a single part
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Description {
+    /// Contents of every `<pre><code>` block, in order
+    pub blocks: Vec<String>,
+    /// Contents of every `<code><em>` span, in order
+    pub emphasized: Vec<String>,
+}
+
+impl Description {
+    /// The example input, i.e. the only code block. Parts with several blocks are ambiguous,
+    /// as they hold more examples or intermediate steps which each come with their own answers.
+    pub fn sample(&self) -> Option<&str> {
+        match self.blocks.as_slice() {
+            [block] => Some(block),
+            _ => None,
+        }
+    }
+
+    /// The last emphasized code span, which by convention is the answer for the example
+    pub fn answer(&self) -> Option<&str> {
+        self.emphasized.last().map(String::as_str)
+    }
+}
+
+/// A puzzle page as saved from the browser. The second part only appears once the first one
+/// has been solved.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Puzzle {
+    pub parts: Vec<Description>,
+}
+
+impl Puzzle {
+    /// Extracts the descriptions from a puzzle page. Pages without `<article>` elements are
+    /// treated as a single description.
+    pub fn parse(html: &str) -> Self {
+        let mut articles: Vec<_> = sections(html, "<article", "</article>").collect();
+        if articles.is_empty() {
+            articles.push(html);
+        }
+        let parts = articles
+            .into_iter()
+            .map(|article| Description {
+                blocks: sections(article, "<pre><code>", "</code></pre>")
+                    .map(|block| decode(&strip_tags(block)))
+                    .collect(),
+                emphasized: sections(article, "<code><em>", "</em></code>")
+                    .map(|span| decode(&strip_tags(span)))
+                    .collect(),
+            })
+            .collect();
+        Self { parts }
+    }
+
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        std::fs::read_to_string(path).map(|html| Self::parse(&html))
+    }
+
+    /// Example input and expected answer of both parts, where they can be told apart.
+    ///
+    /// Part one needs a single [sample][Description::sample]. Part two reuses it as long as it
+    /// shows no code blocks of its own, which could be a new example as well as intermediate
+    /// steps. Days where that is too strict have to pick from [parts][Puzzle::parts] themselves.
+    pub fn samples(&self) -> [Option<(&str, &str)>; 2] {
+        let sample = self.parts.first().and_then(Description::sample);
+        let first = sample.zip(self.parts.first().and_then(Description::answer));
+        let second = self
+            .parts
+            .get(1)
+            .filter(|part| part.blocks.is_empty())
+            .and_then(|part| sample.zip(part.answer()));
+        [first, second]
+    }
+}
+
+/// `<dir>/<year>/<day>.html`, next to the cached input
+pub fn puzzle_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("{day}.html"))
+}
+
+/// Contents between each `start` and the following `end`. A `start` ending in an unclosed tag
+/// (e.g. `<article`) skips the rest of that tag.
+fn sections<'a>(s: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        let from = rest.find(start)? + start.len();
+        let mut body = &rest[from..];
+        if !start.ends_with('>') {
+            body = &body[body.find('>')? + 1..];
+        }
+        let to = body.find(end)?;
+        rest = &body[to + end.len()..];
+        Some(&body[..to])
+    })
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Decodes the named entities used on puzzle pages as well as numeric ones
+fn decode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(i) = rest.find('&') {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let Some(end) = rest.find(';').filter(|&end| end <= 10) else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>For example:</p>
+<pre><code>3   4
+4   3
+&lt;x&gt; &amp; <em>y</em>
+</code></pre>
+<p>Pairs like <code>3</code> add up to <code><em>11</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The similarity score is <code><em>31</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn parse() {
+        let puzzle = Puzzle::parse(PAGE);
+        assert_eq!(puzzle.parts.len(), 2);
+        assert_eq!(puzzle.parts[0].sample(), Some("3   4\n4   3\n<x> & y\n"));
+        assert_eq!(puzzle.parts[0].answer(), Some("11"));
+        assert_eq!(puzzle.parts[1].blocks, Vec::<String>::new());
+
+        let [first, second] = puzzle.samples();
+        assert_eq!(first, Some(("3   4\n4   3\n<x> & y\n", "11")));
+        assert_eq!(second, Some(("3   4\n4   3\n<x> & y\n", "31")));
+    }
+
+    #[test]
+    fn several_examples() {
+        // Like 2024 day 12, where each example has its own answer and part two walks through
+        // another one
+        let page = r#"<article class="day-desc">
+<pre><code>AAAA
+BBCD
+</code></pre>
+<p>Region <code>A</code> has area <code>4</code>.</p>
+<pre><code>A region with price 4 * 10 = 40
+</code></pre>
+<p>The total price is <code><em>140</em></code>.</p>
+<pre><code>RRRR
+RRII
+</code></pre>
+<p>It has a total price of <code><em>1930</em></code>.</p>
+</article>
+<article class="day-desc"><p>The first example now costs <code><em>80</em></code>.</p>
+<pre><code>EEEEE
+EXXXX
+</code></pre>
+<p>That gives <code><em>236</em></code>, and the larger example <code><em>1206</em></code>.</p>
+</article>"#;
+        let puzzle = Puzzle::parse(page);
+        assert_eq!(puzzle.parts[0].blocks.len(), 3);
+        assert_eq!(puzzle.parts[0].sample(), None);
+        assert_eq!(puzzle.parts[0].answer(), Some("1930"));
+        assert_eq!(puzzle.samples(), [None, None]);
+
+        // Part two showing a code block of its own no longer reuses the example of part one
+        let steps = PAGE.replace(
+            "<p>The similarity",
+            "<pre><code>3 4</code></pre><p>The similarity",
+        );
+        let puzzle = Puzzle::parse(&steps);
+        let [first, second] = puzzle.samples();
+        assert_eq!(first.map(|(_, answer)| answer), Some("11"));
+        assert_eq!(second, None);
+    }
+
+    #[test]
+    fn entities() {
+        assert_eq!(decode("a &lt;&#62;&#x3c; &b; & c"), "a <>< &b; & c");
+        assert_eq!(strip_tags("<em>1</em>2"), "12");
+    }
+}
@@ -0,0 +1,506 @@
+//! Parsing lines against a format pattern, see [scan!][crate::scan].
+//!
+//! Patterns are literal text with placeholders:
+//! - `{}` a value whose type is inferred from the output
+//! - `{u32}`, `{&str}`, ... a value which must be of the named type
+//! - `{list:SEP}` a [Vec] of values separated by `SEP`, `{list}` separates by `", "`
+//! - `{_}` text which is skipped
+//! - `{{` and `}}` literal braces
+//!
+//! Numbers, chars and bools take as much of the input as they can. Other placeholders
+//! extend up to the next literal text of the pattern, or to the end of the input.
+
+use std::fmt::Display;
+
+use super::{ParseResult, Source};
+
+/// A compiled pattern, which can be reused across lines
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Pattern<'p> {
+    pieces: Vec<Piece<'p>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Piece<'p> {
+    Literal(String),
+    Field(Spec<'p>),
+    Skip,
+}
+
+/// What a value placeholder asks for
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Spec<'p> {
+    Any,
+    Typed(&'p str),
+    List(&'p str),
+}
+
+impl<'p> Pattern<'p> {
+    /// Panics if `pattern` has an unmatched brace
+    pub fn new(pattern: &'p str) -> Self {
+        let mut pieces = vec![];
+        let mut literal = String::new();
+        let mut rest = pattern;
+        while let Some(c) = rest.chars().next() {
+            match c {
+                '{' if rest.starts_with("{{") => {
+                    literal.push('{');
+                    rest = &rest[2..];
+                }
+                '}' if rest.starts_with("}}") => {
+                    literal.push('}');
+                    rest = &rest[2..];
+                }
+                '{' => {
+                    let end = rest
+                        .find('}')
+                        .unwrap_or_else(|| panic!("unclosed placeholder in {pattern:?}"));
+                    if !literal.is_empty() {
+                        pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                    }
+                    pieces.push(match &rest[1..end] {
+                        "" => Piece::Field(Spec::Any),
+                        "_" => Piece::Skip,
+                        "list" => Piece::Field(Spec::List(", ")),
+                        spec => match spec.strip_prefix("list:") {
+                            Some(sep) => Piece::Field(Spec::List(sep)),
+                            None => Piece::Field(Spec::Typed(spec)),
+                        },
+                    });
+                    rest = &rest[end + 1..];
+                }
+                '}' => panic!("unmatched '}}' in {pattern:?}"),
+                c => {
+                    literal.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+        Self { pieces }
+    }
+
+    /// Number of values the pattern produces
+    pub fn fields(&self) -> usize {
+        self.pieces
+            .iter()
+            .filter(|p| matches!(p, Piece::Field(_)))
+            .count()
+    }
+
+    /// Matches all of `input` against the pattern
+    pub fn scan<'a, T: FromScan<'a>>(&self, input: &'a str) -> ParseResult<T> {
+        self.scan_in(&Source::new(input), input)
+    }
+
+    /// Like [scan][Pattern::scan] for `at` within `src`, so errors point into the whole input
+    pub fn scan_in<'a, T: FromScan<'a>>(&self, src: &Source<'a>, at: &'a str) -> ParseResult<T> {
+        assert_eq!(
+            self.fields(),
+            T::FIELDS,
+            "pattern has {} placeholders but {} values are expected",
+            self.fields(),
+            T::FIELDS
+        );
+        let mut scanner = Scanner {
+            src,
+            pieces: &self.pieces,
+            rest: at,
+        };
+        let value = T::from_scan(&mut scanner)?;
+        scanner.finish()?;
+        Ok(value)
+    }
+}
+
+/// Progress of matching a pattern against an input
+pub struct Scanner<'s, 'p, 'a> {
+    src: &'s Source<'a>,
+    pieces: &'s [Piece<'p>],
+    rest: &'a str,
+}
+
+impl<'p, 'a> Scanner<'_, 'p, 'a> {
+    fn literal(&mut self, literal: &str) -> ParseResult<()> {
+        let common: usize = self
+            .rest
+            .chars()
+            .zip(literal.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+        if common == literal.len() {
+            self.rest = &self.rest[common..];
+            return Ok(());
+        }
+        let diverging = &self.rest[common..];
+        let at = &diverging[..diverging.chars().next().map_or(0, char::len_utf8)];
+        Err(self
+            .src
+            .error(at, format!("expected {:?}", &literal[common..])))
+    }
+
+    /// Text up to the next literal of the pattern, or the rest of the input
+    fn delimited(&mut self) -> ParseResult<&'a str> {
+        let end = match self.pieces.first() {
+            Some(Piece::Literal(next)) => self
+                .rest
+                .find(next.as_str())
+                .ok_or_else(|| self.src.error(self.rest, format!("expected {next:?}")))?,
+            Some(_) => panic!("placeholders must be separated by literal text"),
+            None => self.rest.len(),
+        };
+        let (field, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Ok(field)
+    }
+
+    /// Matches up to and including the next value placeholder
+    fn next_field(&mut self) -> ParseResult<Spec<'p>> {
+        while let Some((piece, pieces)) = self.pieces.split_first() {
+            self.pieces = pieces;
+            match piece {
+                Piece::Literal(literal) => self.literal(literal)?,
+                Piece::Skip => {
+                    self.delimited()?;
+                }
+                Piece::Field(spec) => return Ok(*spec),
+            }
+        }
+        unreachable!("field count is checked up front")
+    }
+
+    /// Scans the next value of the pattern
+    pub fn field<T: Scan<'a>>(&mut self) -> ParseResult<T> {
+        let spec = self.next_field()?;
+        match spec {
+            Spec::Typed(name) if name != T::NAME => {
+                panic!("placeholder {{{name}}} is used for a {}", T::NAME)
+            }
+            Spec::List(_) if T::NAME != "list" => {
+                panic!("list placeholder is used for a {}", T::NAME)
+            }
+            _ => {}
+        }
+        let field = match T::extent(self.rest) {
+            Some(len) => {
+                let (field, rest) = self.rest.split_at(len);
+                self.rest = rest;
+                field
+            }
+            None => self.delimited()?,
+        };
+        T::scan(self.src, field, spec)
+    }
+
+    fn finish(mut self) -> ParseResult<()> {
+        while let Some((piece, pieces)) = self.pieces.split_first() {
+            self.pieces = pieces;
+            match piece {
+                Piece::Literal(literal) => self.literal(literal)?,
+                Piece::Skip => {
+                    self.delimited()?;
+                }
+                Piece::Field(_) => unreachable!("field count is checked up front"),
+            }
+        }
+        let rest = self.rest.trim();
+        if rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.src.error(rest, "unexpected trailing input"))
+        }
+    }
+}
+
+/// A value which can fill a placeholder
+pub trait Scan<'a>: Sized {
+    /// Type name used in typed placeholders like `{u32}`
+    const NAME: &'static str;
+
+    /// Length of the value at the start of `input`, or [None] to extend up to the next
+    /// literal text
+    fn extent(_input: &str) -> Option<usize> {
+        None
+    }
+
+    fn scan(src: &Source<'a>, field: &'a str, spec: Spec) -> ParseResult<Self>;
+}
+
+fn parse_field<T>(src: &Source<'_>, field: &str) -> ParseResult<T>
+where
+    T: std::str::FromStr,
+    T::Err: Display,
+{
+    if field.is_empty() {
+        return Err(src.error(field, format!("expected {}", std::any::type_name::<T>())));
+    }
+    src.parse(field)
+}
+
+fn sign_len(input: &str) -> usize {
+    input.starts_with(['-', '+']) as usize
+}
+
+fn digits_len(input: &str) -> usize {
+    input.bytes().take_while(u8::is_ascii_digit).count()
+}
+
+macro_rules! impl_scan_int {
+    ($($t:ident)+) => {
+        $(
+        impl Scan<'_> for $t {
+            const NAME: &'static str = stringify!($t);
+
+            fn extent(input: &str) -> Option<usize> {
+                let sign = sign_len(input);
+                Some(sign + digits_len(&input[sign..]))
+            }
+
+            fn scan(src: &Source<'_>, field: &str, _: Spec) -> ParseResult<Self> {
+                parse_field(src, field)
+            }
+        }
+        )+
+    };
+}
+
+impl_scan_int!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+macro_rules! impl_scan_float {
+    ($($t:ident)+) => {
+        $(
+        impl Scan<'_> for $t {
+            const NAME: &'static str = stringify!($t);
+
+            fn extent(input: &str) -> Option<usize> {
+                let mut len = sign_len(input);
+                len += digits_len(&input[len..]);
+                if input[len..].starts_with('.') {
+                    len += 1 + digits_len(&input[len + 1..]);
+                }
+                if input[len..].starts_with(['e', 'E']) {
+                    let sign = sign_len(&input[len + 1..]);
+                    let exp = digits_len(&input[len + 1 + sign..]);
+                    if exp > 0 {
+                        len += 1 + sign + exp;
+                    }
+                }
+                Some(len)
+            }
+
+            fn scan(src: &Source<'_>, field: &str, _: Spec) -> ParseResult<Self> {
+                parse_field(src, field)
+            }
+        }
+        )+
+    };
+}
+
+impl_scan_float!(f32 f64);
+
+impl Scan<'_> for char {
+    const NAME: &'static str = "char";
+
+    fn extent(input: &str) -> Option<usize> {
+        Some(input.chars().next().map_or(0, char::len_utf8))
+    }
+
+    fn scan(src: &Source<'_>, field: &str, _: Spec) -> ParseResult<Self> {
+        field
+            .chars()
+            .next()
+            .ok_or_else(|| src.error(field, "expected a char"))
+    }
+}
+
+impl Scan<'_> for bool {
+    const NAME: &'static str = "bool";
+
+    fn extent(input: &str) -> Option<usize> {
+        Some(input.bytes().take_while(u8::is_ascii_alphabetic).count())
+    }
+
+    fn scan(src: &Source<'_>, field: &str, _: Spec) -> ParseResult<Self> {
+        parse_field(src, field)
+    }
+}
+
+impl<'a> Scan<'a> for &'a str {
+    const NAME: &'static str = "&str";
+
+    fn scan(_: &Source<'a>, field: &'a str, _: Spec) -> ParseResult<Self> {
+        Ok(field)
+    }
+}
+
+impl Scan<'_> for String {
+    const NAME: &'static str = "String";
+
+    fn scan(_: &Source<'_>, field: &str, _: Spec) -> ParseResult<Self> {
+        Ok(field.to_string())
+    }
+}
+
+impl<'a, T: Scan<'a>> Scan<'a> for Vec<T> {
+    const NAME: &'static str = "list";
+
+    fn scan(src: &Source<'a>, field: &'a str, spec: Spec) -> ParseResult<Self> {
+        let Spec::List(sep) = spec else {
+            panic!("{{list:SEP}} placeholder expected for a Vec");
+        };
+        if field.is_empty() {
+            return Ok(vec![]);
+        }
+        field
+            .split(sep)
+            .map(|item| {
+                if T::extent(item).is_some_and(|len| len != item.len()) {
+                    let rest = &item[T::extent(item).unwrap_or(0)..];
+                    return Err(src.error(rest, format!("expected {sep:?}")));
+                }
+                T::scan(src, item, Spec::Any)
+            })
+            .collect()
+    }
+}
+
+/// Output of a pattern, implemented for tuples of [Scan] values
+pub trait FromScan<'a>: Sized {
+    const FIELDS: usize;
+
+    fn from_scan(scanner: &mut Scanner<'_, '_, 'a>) -> ParseResult<Self>;
+}
+
+macro_rules! impl_from_scan {
+    ($n:literal; $($t:ident),+) => {
+        impl<'a, $($t: Scan<'a>),+> FromScan<'a> for ($($t,)+) {
+            const FIELDS: usize = $n;
+
+            fn from_scan(scanner: &mut Scanner<'_, '_, 'a>) -> ParseResult<Self> {
+                Ok(($(scanner.field::<$t>()?,)+))
+            }
+        }
+    };
+}
+
+impl FromScan<'_> for () {
+    const FIELDS: usize = 0;
+
+    fn from_scan(_: &mut Scanner<'_, '_, '_>) -> ParseResult<Self> {
+        Ok(())
+    }
+}
+
+impl_from_scan!(1; A);
+impl_from_scan!(2; A, B);
+impl_from_scan!(3; A, B, C);
+impl_from_scan!(4; A, B, C, D);
+impl_from_scan!(5; A, B, C, D, E);
+impl_from_scan!(6; A, B, C, D, E, F);
+impl_from_scan!(7; A, B, C, D, E, F, G);
+impl_from_scan!(8; A, B, C, D, E, F, G, H);
+
+/// Parses a string against a format pattern into a tuple or struct,
+/// see [reading::scan][crate::reading::scan] for the pattern syntax.
+///
+/// Evaluates to a [ParseResult][crate::reading::ParseResult]. A mismatch is reported at
+/// the first character which differs from the pattern. Panics if the pattern does not fit
+/// the output type, e.g. has too many placeholders.
+///
+/// ```ignore
+/// let (valve, rate, tunnels): (&str, u32, Vec<&str>) = scan!(
+///     line,
+///     "Valve {} has flow rate={u32}; {_} {_} to {_} {list:, }"
+/// )?;
+/// let button = scan!(line, "Button {_}: X+{}, Y+{}" => Button { x, y })?;
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:literal) => {
+        $crate::reading::scan::Pattern::new($pattern).scan($input)
+    };
+    ($input:expr, $pattern:literal => $ty:ident { $($field:ident),+ $(,)? }) => {
+        $crate::reading::scan::Pattern::new($pattern)
+            .scan($input)
+            .map(|($($field,)+): ($($crate::scan!(@infer $field),)+)| $ty { $($field),+ })
+    };
+    (@infer $field:ident) => {
+        _
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values() {
+        let line = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB";
+        let (valve, rate, tunnels): (&str, u32, Vec<&str>) = crate::scan!(
+            line,
+            "Valve {} has flow rate={u32}; {_} {_} to {_} {list:, }"
+        )
+        .unwrap();
+        assert_eq!((valve, rate, tunnels), ("AA", 0, vec!["DD", "II", "BB"]));
+
+        let line = "Valve HH has flow rate=22; tunnel leads to valve GG";
+        let pattern = Pattern::new("Valve {} has flow rate={}; {_} {_} to {_} {list}");
+        assert_eq!(pattern.scan(line), Ok(("HH", 22u8, vec!["GG"])));
+
+        let (c, f, b, s): (char, f64, bool, String) =
+            crate::scan!("{x} -1.5e2 true!", "{{{}}} {} {}!{}").unwrap();
+        assert_eq!((c, f, b, s.as_str()), ('x', -150.0, true, ""));
+        assert_eq!(
+            crate::scan!("1-2,-3", "{}-{list:,}"),
+            Ok((1, vec![2i8, -3]))
+        );
+    }
+
+    #[test]
+    fn structs() {
+        #[derive(Debug, PartialEq)]
+        struct Button {
+            x: i64,
+            y: i64,
+        }
+        let button =
+            crate::scan!("Button A: X+94, Y+34", "Button {_}: X+{}, Y+{}" => Button { x, y });
+        assert_eq!(button, Ok(Button { x: 94, y: 34 }));
+    }
+
+    #[test]
+    fn mismatch() {
+        let err = crate::scan!("Button A: X+94; Y+34", "Button A: X+{}, Y+{}")
+            .map(|(_, _): (u32, u32)| ())
+            .unwrap_err();
+        assert_eq!((err.column, err.len), (15, 1));
+        assert_eq!(err.message, "expected \", Y+\"");
+
+        let err = Pattern::new("rate={u8}")
+            .scan::<(u8,)>("rate=x")
+            .unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (6, "expected u8"));
+
+        let err = Pattern::new("{list:,}")
+            .scan::<(Vec<u8>,)>("1,2;3")
+            .unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (4, "expected \",\""));
+
+        let src = Source::new("a: 1\nb: 2 3");
+        let line = src.lines().nth(1).unwrap();
+        let err = Pattern::new("{}: {}")
+            .scan_in::<(&str, u8)>(&src, line)
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(err.message, "unexpected trailing input");
+    }
+
+    #[test]
+    #[should_panic = "placeholder {u32} is used for a i64"]
+    fn wrong_type() {
+        let _ = Pattern::new("{u32}").scan::<(i64,)>("1");
+    }
+}
@@ -0,0 +1,206 @@
+//! Inputs made of several blocks separated by blank lines, like a map followed by moves.
+
+use super::error::count;
+use super::{ParseError, ParseResult, Source};
+
+/// Blocks of `input` separated by one or more blank lines, see [sections]
+#[derive(Debug, Clone)]
+pub struct Sections<'a> {
+    rest: &'a str,
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Skip blank lines before the block
+        while let Some((line, rest)) = self.rest.split_once('\n') {
+            if !is_blank(line) {
+                break;
+            }
+            self.rest = rest;
+        }
+        if is_blank(self.rest) {
+            self.rest = "";
+            return None;
+        }
+
+        let mut end = 0;
+        let block = loop {
+            match self.rest[end..].find('\n') {
+                Some(i) => {
+                    let line_end = end + i;
+                    let next = &self.rest[line_end + 1..];
+                    let next_line = next.split_once('\n').map_or(next, |(line, _)| line);
+                    if is_blank(next_line) {
+                        let block = &self.rest[..line_end];
+                        self.rest = next;
+                        break block;
+                    }
+                    end = line_end + 1;
+                }
+                None => break std::mem::take(&mut self.rest),
+            }
+        };
+        Some(block.trim_end_matches('\r'))
+    }
+}
+
+/// Splits `input` on blank lines, yielding each block without its final line break.
+///
+/// Lines may end in `\n` or `\r\n` and leading or trailing blank lines are ignored.
+/// Blocks are subslices of `input`, so errors reported with a [Source] of the whole
+/// input point at the right line.
+pub fn sections(input: &str) -> Sections<'_> {
+    Sections { rest: input }
+}
+
+/// The `i`th of `n` expected sections
+fn expect_section<'a>(
+    blocks: &mut Sections<'a>,
+    input: &'a str,
+    n: usize,
+    i: usize,
+) -> ParseResult<&'a str> {
+    blocks.next().ok_or_else(|| {
+        Source::new(input).eof(format!("expected {}, found {i}", count(n, "section")))
+    })
+}
+
+/// Checks there are no sections left after `n`
+fn expect_end(mut blocks: Sections<'_>, input: &str, n: usize) -> ParseResult<()> {
+    match blocks.next() {
+        Some(extra) => {
+            let found = n + 1 + blocks.count();
+            let first_line = extra.lines().next().unwrap_or(extra);
+            Err(Source::new(input).error(
+                first_line,
+                format!("expected {}, found {found}", count(n, "section")),
+            ))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Exactly `N` sections of `input`
+///
+/// ```ignore
+/// let [rules, updates] = sections_n(input)?;
+/// ```
+pub fn sections_n<const N: usize>(input: &str) -> ParseResult<[&str; N]> {
+    let mut blocks = sections(input);
+    let mut found = [""; N];
+    for (i, slot) in found.iter_mut().enumerate() {
+        *slot = expect_section(&mut blocks, input, N, i)?;
+    }
+    expect_end(blocks, input, N)?;
+    Ok(found)
+}
+
+/// Moves an error reported within `block` to where `block` lies in `input`
+fn relocate(err: ParseError, input: &str, block: &str) -> ParseError {
+    let offset = (block.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+    let lines = input
+        .get(..offset)
+        .map_or(0, |before| before.matches('\n').count());
+    ParseError {
+        line: err.line + lines,
+        ..err
+    }
+}
+
+/// A tuple of section parsers, each a `Fn(&str) -> ParseResult<T>` for its own block
+pub trait SectionParsers<'a> {
+    type Output;
+
+    fn parse_sections(&self, input: &'a str) -> ParseResult<Self::Output>;
+}
+
+macro_rules! impl_section_parsers {
+    ($n:literal; $($p:ident $t:ident $idx:tt),+) => {
+        impl<'a, $($p, $t),+> SectionParsers<'a> for ($($p,)+)
+        where
+            $($p: Fn(&'a str) -> ParseResult<$t>,)+
+        {
+            type Output = ($($t,)+);
+
+            fn parse_sections(&self, input: &'a str) -> ParseResult<Self::Output> {
+                let mut blocks = sections(input);
+                let output = ($({
+                    let block = expect_section(&mut blocks, input, $n, $idx)?;
+                    (self.$idx)(block).map_err(|err| relocate(err, input, block))?
+                },)+);
+                expect_end(blocks, input, $n)?;
+                Ok(output)
+            }
+        }
+    };
+}
+
+impl_section_parsers!(1; PA A 0);
+impl_section_parsers!(2; PA A 0, PB B 1);
+impl_section_parsers!(3; PA A 0, PB B 1, PC C 2);
+impl_section_parsers!(4; PA A 0, PB B 1, PC C 2, PD D 3);
+impl_section_parsers!(5; PA A 0, PB B 1, PC C 2, PD D 3, PE E 4);
+
+/// Parses each section of `input` with the corresponding parser of `parsers`.
+///
+/// Parsers only see their own block, errors they return are moved to the block's
+/// position in `input`. Sections are parsed in order, so an error in an earlier section
+/// is reported before a missing later one.
+///
+/// ```ignore
+/// let (map, moves) = parse_sections(input, (parse_map, parse_moves))?;
+/// ```
+pub fn parse_sections<'a, P: SectionParsers<'a>>(
+    input: &'a str,
+    parsers: P,
+) -> ParseResult<P::Output> {
+    parsers.parse_sections(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        let input = "\na\nb\r\n\r\n\r\nc\n  \nd\r\n\n";
+        assert_eq!(sections(input).collect::<Vec<_>>(), ["a\nb", "c", "d"]);
+        assert_eq!(sections("x").collect::<Vec<_>>(), ["x"]);
+        assert_eq!(sections("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn fixed() {
+        assert_eq!(sections_n("1\n\n2\n"), Ok(["1", "2"]));
+        let err = sections_n::<2>("1\n2\n").unwrap_err();
+        assert_eq!(err.message, "expected 2 sections, found 1");
+        let err = sections_n::<1>("1\n\n2\n3\n\n4").unwrap_err();
+        assert_eq!(
+            (err.line, err.message.as_str()),
+            (3, "expected 1 section, found 3")
+        );
+    }
+
+    #[test]
+    fn typed() {
+        let number = |block: &str| Source::new(block).parse::<u32>(block);
+        let list = |block: &str| {
+            let src = Source::new(block);
+            block
+                .lines()
+                .map(|l| src.parse::<i8>(l))
+                .collect::<ParseResult<Vec<_>>>()
+        };
+        let input = "7\n\n1\n-2\n";
+        assert_eq!(parse_sections(input, (number, list)), Ok((7, vec![1, -2])));
+
+        let err = parse_sections("7\n\n1\nx\n", (number, list)).unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (4, 1, "x"));
+    }
+}
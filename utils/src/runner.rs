@@ -0,0 +1,374 @@
+use std::any::TypeId;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::bench::{self, BenchConfig, Stats};
+use crate::rand::Rng;
+use crate::reading::ParseResult;
+use crate::reading::input::{self, InputError, InputSource};
+
+pub mod check;
+
+/// A solution to a single day's puzzle.
+///
+/// Input is parsed once with [parse][Solution::parse] and then handed to both parts.
+/// Days which parse inside of each part can simply pass the raw `&str` through.
+/// Parsing may fail with a [ParseError][crate::reading::ParseError] pointing into the input.
+pub trait Solution {
+    /// Parsed puzzle input
+    type Input<'a>;
+
+    type Answer1: Display + 'static;
+    type Answer2: Display + 'static;
+
+    fn parse(input: &str) -> ParseResult<Self::Input<'_>>;
+
+    fn part1(input: &Self::Input<'_>) -> Self::Answer1;
+
+    fn part2(input: &Self::Input<'_>) -> Self::Answer2;
+}
+
+/// Answer type for parts which are not solved (or were solved by hand).
+/// The runner skips parts answering with this type.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn iter_all() -> impl Iterator<Item = Self> {
+        [Self::One, Self::Two].into_iter()
+    }
+
+    pub fn from_num(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    pub fn num(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.num())
+    }
+}
+
+/// Output of running one part of a [Day]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Run {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// Alternative implementation of a part, e.g. a naive version kept next to an optimized one.
+///
+/// Construct with the [variant][crate::variant] macro.
+#[derive(Debug, Clone, Copy)]
+pub struct Variant {
+    pub name: &'static str,
+    pub part: Part,
+    /// Parses and solves in one go, returning the displayed answer
+    pub solve: fn(&str) -> String,
+}
+
+/// Timings of a [Solution] with parsing measured apart from solving
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SolutionBench {
+    pub parse: Stats,
+    pub solve: Stats,
+    /// Parsing, solving and displaying the answer in one go, comparable with a [Variant]
+    pub total: Stats,
+}
+
+/// Type-erased [Solution] registered under a year and day.
+///
+/// Construct with the [day][crate::day] macro so that the crate's manifest directory is captured.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// `CARGO_MANIFEST_DIR` of the crate implementing the solution
+    pub manifest_dir: &'static str,
+    pub variants: &'static [Variant],
+    /// Sample inputs from the puzzle text, used for cross checking variants
+    pub samples: &'static [&'static str],
+    /// Generates random inputs for cross checking variants
+    pub generator: Option<fn(&mut Rng) -> String>,
+    solve: fn(&str, Part) -> ParseResult<Option<Run>>,
+    bench: fn(&str, Part, BenchConfig) -> ParseResult<Option<SolutionBench>>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u16, day: u8, manifest_dir: &'static str) -> Self {
+        Self {
+            year,
+            day,
+            manifest_dir,
+            variants: &[],
+            samples: &[],
+            generator: None,
+            solve: solve::<S>,
+            bench: bench_solution::<S>,
+        }
+    }
+
+    /// Registers alternative implementations of the parts
+    pub const fn with_variants(mut self, variants: &'static [Variant]) -> Self {
+        self.variants = variants;
+        self
+    }
+
+    /// Registers sample inputs valid for both parts
+    pub const fn with_samples(mut self, samples: &'static [&'static str]) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Registers a generator of random inputs valid for both parts
+    pub const fn with_generator(mut self, generator: fn(&mut Rng) -> String) -> Self {
+        self.generator = Some(generator);
+        self
+    }
+
+    pub fn variants_of(&self, part: Part) -> impl Iterator<Item = &'static Variant> {
+        self.variants.iter().filter(move |v| v.part == part)
+    }
+
+    /// Benchmarks parsing and solving a single part.
+    /// Returns [None] when the part is [Unsolved].
+    pub fn bench(
+        &self,
+        input: &str,
+        part: Part,
+        config: BenchConfig,
+    ) -> ParseResult<Option<SolutionBench>> {
+        (self.bench)(input, part, config)
+    }
+
+    /// Runs a single part against `input`.
+    /// Returns [None] when the part is [Unsolved].
+    pub fn solve(&self, input: &str, part: Part) -> ParseResult<Option<Run>> {
+        (self.solve)(input, part)
+    }
+
+    /// Legacy location of the puzzle input, next to the solution's sources
+    pub fn legacy_input_path(&self) -> PathBuf {
+        [self.manifest_dir, "src", "input.txt"].iter().collect()
+    }
+
+    /// Reads the puzzle input from `source`.
+    /// Inputs missing from the cache fall back to [legacy_input_path][Day::legacy_input_path].
+    pub fn load_input(&self, source: &InputSource) -> Result<String, InputError> {
+        match input::load(self.year, self.day, source) {
+            Err(InputError::Missing { .. }) if self.legacy_input_path().is_file() => input::load(
+                self.year,
+                self.day,
+                &InputSource::Path(self.legacy_input_path()),
+            ),
+            res => res,
+        }
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+fn is_solved<S: Solution>(part: Part) -> bool {
+    match part {
+        Part::One => TypeId::of::<S::Answer1>() != TypeId::of::<Unsolved>(),
+        Part::Two => TypeId::of::<S::Answer2>() != TypeId::of::<Unsolved>(),
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: Part) -> ParseResult<Option<Run>> {
+    if !is_solved::<S>(part) {
+        return Ok(None);
+    }
+    let now = Instant::now();
+    let parsed = S::parse(input)?;
+    let answer = match part {
+        Part::One => S::part1(&parsed).to_string(),
+        Part::Two => S::part2(&parsed).to_string(),
+    };
+    Ok(Some(Run {
+        answer,
+        elapsed: now.elapsed(),
+    }))
+}
+
+fn bench_solution<S: Solution>(
+    input: &str,
+    part: Part,
+    config: BenchConfig,
+) -> ParseResult<Option<SolutionBench>> {
+    if !is_solved::<S>(part) {
+        return Ok(None);
+    }
+    let parsed = S::parse(input)?;
+    let parse = bench::bench(config, || S::parse(input));
+    let (solve, total) = match part {
+        Part::One => (
+            bench::bench(config, || S::part1(&parsed)),
+            bench::bench(config, || S::parse(input).map(|p| S::part1(&p).to_string())),
+        ),
+        Part::Two => (
+            bench::bench(config, || S::part2(&parsed)),
+            bench::bench(config, || S::parse(input).map(|p| S::part2(&p).to_string())),
+        ),
+    };
+    Ok(Some(SolutionBench {
+        parse,
+        solve,
+        total,
+    }))
+}
+
+/// Constructs a [Day][crate::runner::Day] for a [Solution][crate::runner::Solution] implemented in
+/// the calling crate
+///
+/// ```ignore
+/// pub const DAY: Day = aocutils::day!(2024, 16, Day16);
+/// ```
+#[macro_export]
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:ty) => {
+        $crate::runner::Day::new::<$solution>($year, $day, env!("CARGO_MANIFEST_DIR"))
+    };
+}
+
+/// Constructs a [Variant][crate::runner::Variant] from a function taking the raw input
+///
+/// ```ignore
+/// aocutils::variant!(One, "naive", p1::naive::part1)
+/// ```
+#[macro_export]
+macro_rules! variant {
+    ($part:ident, $name:literal, $f:path) => {
+        $crate::runner::Variant {
+            name: $name,
+            part: $crate::runner::Part::$part,
+            solve: |input| $f(input).to_string(),
+        }
+    };
+}
+
+/// Prints the answers of every solved part of `day`, stopping if the input is invalid
+pub fn print_day(day: &Day, input: &str, parts: impl Iterator<Item = Part>) -> ParseResult<()> {
+    for part in parts {
+        match day.solve(input, part)? {
+            Some(run) => println!(
+                "{day} part {part}: {} ({} μs)",
+                run.answer,
+                run.elapsed.as_micros()
+            ),
+            None => println!("{day} part {part}: unsolved"),
+        }
+    }
+    Ok(())
+}
+
+/// Loads the input of `day`, exiting the process with a message if it cannot be read.
+///
+/// The first command line argument, if any, is used as the input path (`-` for stdin).
+pub fn load_input_or_exit(day: &Day) -> String {
+    let source = InputSource::resolve(std::env::args().nth(1).as_deref());
+    match day.load_input(&source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{day}: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Entry point for a single day's binary
+pub fn main(day: &Day) {
+    let input = load_input_or_exit(day);
+    if let Err(e) = print_day(day, &input, Part::iter_all()) {
+        eprintln!("{day}: invalid input, {e}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reading::Source;
+
+    pub(super) struct Sum;
+
+    impl Solution for Sum {
+        type Input<'a> = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = Unsolved;
+
+        fn parse(input: &str) -> ParseResult<Self::Input<'_>> {
+            let src = Source::new(input);
+            src.lines().map(|l| src.parse(l)).collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Self::Answer1 {
+            input.iter().sum()
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> Self::Answer2 {
+            Unsolved
+        }
+    }
+
+    pub(super) fn sum_str(input: &str) -> u32 {
+        input.lines().map(|l| l.parse::<u32>().unwrap()).sum()
+    }
+
+    #[test]
+    fn erased() {
+        let day = crate::day!(2000, 1, Sum);
+        assert_eq!(
+            day.solve("1\n2\n3", Part::One).unwrap().unwrap().answer,
+            "6"
+        );
+        assert_eq!(day.solve("1\n2\n3", Part::Two), Ok(None));
+        assert_eq!(day.solve("1\nx", Part::One).unwrap_err().line, 2);
+        let config = BenchConfig {
+            warmup: 0,
+            iterations: 3,
+        };
+        let timings = day.bench("1\n2", Part::One, config).unwrap().unwrap();
+        assert_eq!(timings.solve.samples, 3);
+        assert_eq!(timings.total.samples, 3);
+        assert_eq!(day.bench("1\n2", Part::Two, config), Ok(None));
+        assert!(day.legacy_input_path().ends_with("src/input.txt"));
+    }
+
+    #[test]
+    fn variants() {
+        const DAY: Day =
+            crate::day!(2000, 1, Sum).with_variants(&[crate::variant!(One, "str", sum_str)]);
+        assert_eq!(DAY.variants_of(Part::Two).count(), 0);
+        let variant = DAY.variants_of(Part::One).next().unwrap();
+        assert_eq!((variant.solve)("1\n2\n3"), "6");
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt::Display;
+
+use super::{Day, Part};
+use crate::rand::Rng;
+use crate::reading::input::{self, InputError, InputSource};
+use crate::reading::puzzle::{self, Puzzle};
+
+/// Name under which the [Solution][super::Solution] itself is reported
+pub const SOLUTION: &str = "solution";
+
+/// Which input a cross check ran on
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CheckInput {
+    /// Index into [Day::samples]
+    Sample(usize),
+    /// The real puzzle input
+    Input,
+    /// Generated with [Day::generator] from this seed
+    Random(u64),
+}
+
+impl Display for CheckInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sample(i) => write!(f, "sample {}", i + 1),
+            Self::Input => write!(f, "input"),
+            Self::Random(seed) => write!(f, "random input (seed {seed})"),
+        }
+    }
+}
+
+/// Implementations of a part which disagree on an input
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Divergence {
+    pub part: Part,
+    pub input: CheckInput,
+    /// Answer of every implementation, the solution first
+    pub answers: Vec<(&'static str, String)>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part {} disagrees on {}:", self.part, self.input)?;
+        for (i, (name, answer)) in self.answers.iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            write!(f, "{sep}{name} = {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct CheckConfig {
+    /// Number of generated inputs, ignored for days without a generator
+    pub random: usize,
+    /// Seed of the first generated input, following inputs increment it
+    pub seed: u64,
+}
+
+impl Day {
+    /// Answers of the solution (unless [Unsolved][super::Unsolved]) and of every variant of `part`
+    pub fn answers(&self, input: &str, part: Part) -> Vec<(&'static str, String)> {
+        let solution = match self.solve(input, part) {
+            Ok(run) => run.map(|run| (SOLUTION, run.answer)),
+            Err(e) => Some((SOLUTION, format!("invalid input, {e}"))),
+        };
+        solution
+            .into_iter()
+            .chain(self.variants_of(part).map(|v| (v.name, (v.solve)(input))))
+            .collect()
+    }
+
+    /// Runs every implementation of `part` on `input`, returning the answer they agree on
+    pub fn cross_check_input(
+        &self,
+        input: &str,
+        which: CheckInput,
+        part: Part,
+    ) -> Result<Option<String>, Divergence> {
+        let answers = self.answers(input, part);
+        match answers.split_first() {
+            None => Ok(None),
+            Some(((_, first), rest)) if rest.iter().all(|(_, a)| a == first) => {
+                Ok(Some(first.clone()))
+            }
+            Some(_) => Err(Divergence {
+                part,
+                input: which,
+                answers,
+            }),
+        }
+    }
+
+    /// Cross checks every part with variants on the samples, on `input` if given and on
+    /// generated inputs.
+    ///
+    /// Stops at the first divergence, otherwise returns the number of inputs checked per part.
+    pub fn cross_check(
+        &self,
+        input: Option<&str>,
+        config: CheckConfig,
+    ) -> Result<usize, Divergence> {
+        let samples = self
+            .samples
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (CheckInput::Sample(i), s.to_string()));
+        let real = input.map(|s| (CheckInput::Input, s.to_string()));
+        let random = self.generator.into_iter().flat_map(|generate| {
+            (0..config.random as u64).map(move |i| {
+                let seed = config.seed.wrapping_add(i);
+                (CheckInput::Random(seed), generate(&mut Rng::new(seed)))
+            })
+        });
+
+        let parts: Vec<_> = Part::iter_all()
+            .filter(|&p| self.variants_of(p).next().is_some())
+            .collect();
+        let mut checked = 0;
+        for (which, input) in samples.chain(real).chain(random) {
+            for &part in &parts {
+                self.cross_check_input(&input, which, part)?;
+            }
+            checked += 1;
+        }
+        Ok(checked)
+    }
+}
+
+/// The cached input of `day` for tests running on the real input, [None] when it is missing
+/// so those tests can be skipped. Panics on any other error.
+pub fn test_input(day: &Day) -> Option<String> {
+    match day.load_input(&InputSource::Cache) {
+        Ok(input) => Some(input),
+        Err(InputError::Missing { .. }) => {
+            eprintln!("{day}: no input, skipping it");
+            None
+        }
+        Err(e) => panic!("{day}: {e}"),
+    }
+}
+
+/// Backs the [cross_check][crate::cross_check] macro.
+/// Panics on the first divergence, skipping the real input if it is not available.
+pub fn assert_agree(day: &Day, random: usize) {
+    let input = test_input(day);
+    let config = CheckConfig { random, seed: 0 };
+    if let Err(divergence) = day.cross_check(input.as_deref(), config) {
+        panic!("{day}: {divergence}");
+    }
+}
+
+/// Generates a test asserting that the solution and the variants of a [Day][crate::runner::Day]
+/// agree on its samples, its input (when available) and optionally on generated inputs
+///
+/// ```ignore
+/// aocutils::cross_check!(DAY);
+/// aocutils::cross_check!(DAY, random: 100);
+/// ```
+#[macro_export]
+macro_rules! cross_check {
+    ($day:expr) => {
+        $crate::cross_check!($day, random: 0);
+    };
+    ($day:expr, random: $n:expr) => {
+        #[test]
+        fn cross_check() {
+            $crate::runner::check::assert_agree(&$day, $n);
+        }
+    };
+}
+
+/// Backs the [sample_tests][crate::sample_tests] macro.
+/// Panics unless every solved part gives the expected answer for the examples of the saved
+/// puzzle page, see [puzzle_path][puzzle::puzzle_path]. Days without a saved page are skipped.
+pub fn assert_samples(day: &Day) {
+    let path = puzzle::puzzle_path(&input::input_dir(), day.year, day.day);
+    let Ok(puzzle) = Puzzle::load(&path) else {
+        eprintln!("{day}: no puzzle page at {}, skipping", path.display());
+        return;
+    };
+    for (part, sample) in Part::iter_all().zip(puzzle.samples()) {
+        let Some((sample, expected)) = sample else {
+            continue;
+        };
+        let run = day
+            .solve(sample, part)
+            .unwrap_or_else(|e| panic!("{day}: invalid example, {e}"));
+        if let Some(run) = run {
+            assert_eq!(run.answer, expected, "{day} part {part} on the example");
+        }
+    }
+}
+
+/// Generates a test checking a [Day][crate::runner::Day] against the examples and answers
+/// of its saved puzzle page
+///
+/// ```ignore
+/// aocutils::sample_tests!(DAY);
+/// ```
+#[macro_export]
+macro_rules! sample_tests {
+    ($day:expr) => {
+        #[test]
+        fn sample_tests() {
+            $crate::runner::check::assert_samples(&$day);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::tests::{Sum, sum_str};
+
+    fn sum_off_by_one(input: &str) -> u32 {
+        sum_str(input) + u32::from(input.lines().count() > 2)
+    }
+
+    fn generate(rng: &mut Rng) -> String {
+        (0..rng.range(1..5))
+            .map(|_| rng.range(0..10).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn agree() {
+        const DAY: Day = crate::day!(2000, 1, Sum)
+            .with_variants(&[crate::variant!(One, "str", sum_str)])
+            .with_samples(&["1\n2"])
+            .with_generator(generate);
+        let config = CheckConfig {
+            random: 10,
+            seed: 3,
+        };
+        assert_eq!(DAY.cross_check(Some("4\n5\n6"), config), Ok(12));
+        assert_eq!(
+            DAY.cross_check_input("1", CheckInput::Input, Part::Two),
+            Ok(None)
+        );
+        assert_agree(&DAY, 10);
+    }
+
+    #[test]
+    fn diverge() {
+        const DAY: Day = crate::day!(2000, 1, Sum)
+            .with_variants(&[
+                crate::variant!(One, "str", sum_str),
+                crate::variant!(One, "off", sum_off_by_one),
+            ])
+            .with_samples(&["1\n2"]);
+        let divergence = DAY
+            .cross_check(Some("1\n2\n3"), CheckConfig::default())
+            .unwrap_err();
+        assert_eq!(divergence.input, CheckInput::Input);
+        assert_eq!(
+            divergence.to_string(),
+            "part 1 disagrees on input: solution = 6, str = 6, off = 7"
+        );
+    }
+}